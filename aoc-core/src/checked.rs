@@ -0,0 +1,73 @@
+//! Overflow-checked arithmetic for accumulating answers.
+//!
+//! Generated and oversized inputs can push sums, products and least common
+//! multiples past what their type holds, and release builds would silently
+//! wrap. Solvers accumulate through these helpers instead and give up with
+//! [`Overflow`].
+
+use std::{error::Error, fmt::Display};
+
+use num::{CheckedAdd, CheckedMul, CheckedSub, Integer, One, Zero};
+
+/// An answer, or a step towards it, did not fit in the type it is computed in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "arithmetic overflow")
+    }
+}
+
+impl Error for Overflow {}
+
+/// Adds `a` and `b`, failing instead of wrapping.
+pub fn add<T: CheckedAdd>(a: T, b: T) -> Result<T, Overflow> {
+    a.checked_add(&b).ok_or(Overflow)
+}
+
+/// Subtracts `b` from `a`, failing instead of wrapping.
+pub fn sub<T: CheckedSub>(a: T, b: T) -> Result<T, Overflow> {
+    a.checked_sub(&b).ok_or(Overflow)
+}
+
+/// Multiplies `a` and `b`, failing instead of wrapping.
+pub fn mul<T: CheckedMul>(a: T, b: T) -> Result<T, Overflow> {
+    a.checked_mul(&b).ok_or(Overflow)
+}
+
+/// The least common multiple of `a` and `b`, failing instead of wrapping.
+pub fn lcm<T: Integer + CheckedMul + Copy>(a: T, b: T) -> Result<T, Overflow> {
+    if a.is_zero() || b.is_zero() {
+        return Ok(T::zero());
+    }
+    mul(a / a.gcd(&b), b)
+}
+
+/// Sums and products of an iterator that stop at the first overflow.
+pub trait CheckedIterator: Iterator + Sized {
+    fn checked_sum(mut self) -> Result<Self::Item, Overflow>
+    where
+        Self::Item: CheckedAdd + Zero,
+    {
+        self.try_fold(Self::Item::zero(), add)
+    }
+
+    /// Sums terms that may have overflowed on their own already.
+    fn try_checked_sum<T>(mut self) -> Result<T, Overflow>
+    where
+        Self: Iterator<Item = Result<T, Overflow>>,
+        T: CheckedAdd + Zero,
+    {
+        self.try_fold(T::zero(), |total, term| add(total, term?))
+    }
+
+    fn checked_product(mut self) -> Result<Self::Item, Overflow>
+    where
+        Self::Item: CheckedMul + One,
+    {
+        self.try_fold(Self::Item::one(), mul)
+    }
+}
+
+impl<I: Iterator> CheckedIterator for I {}
@@ -0,0 +1,97 @@
+use std::{any::type_name, fmt::Display, io, process};
+
+use clap::{ArgAction, Parser, ValueEnum};
+
+use crate::{
+    logging,
+    params::{self, Params},
+    solution::PartError,
+    Solution,
+};
+
+/// Which half of a puzzle to solve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl Part {
+    /// Runs the selected parts, printing one answer per line.
+    pub fn run<A: Display, B: Display>(self, part1: impl FnOnce() -> A, part2: impl FnOnce() -> B) {
+        if self != Part::Two {
+            println!("{}", part1());
+        }
+        if self != Part::One {
+            println!("{}", part2());
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+struct Args {
+    /// Part of the puzzle to solve.
+    #[arg(long, value_enum, default_value_t = Part::Both)]
+    part: Part,
+    /// Log solver diagnostics to stderr; repeat for more detail.
+    #[arg(short, long, action = ArgAction::Count)]
+    verbose: u8,
+    /// Override a puzzle constant, e.g. `--param steps=6`. Repeatable.
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = params::parse_assignment)]
+    params: Vec<(String, String)>,
+}
+
+/// Parses the command line of a day binary, sets up logging and returns the
+/// requested part along with any parameter overrides.
+pub fn args() -> (Part, Params) {
+    let Args {
+        part,
+        verbose,
+        params,
+    } = Args::parse();
+    logging::init(verbose);
+    (part, params.into_iter().collect())
+}
+
+/// Entry point of a day binary: solves the puzzle read from stdin.
+pub fn main<S: Solution>() {
+    let (part, params) = args();
+    let part = match part {
+        Part::Both if !S::PART2 => Part::One,
+        part => part,
+    };
+    let input = io::read_to_string(io::stdin()).unwrap();
+    let input = params
+        .consumed_by(|params| S::parse_with(&input, params))
+        .unwrap_or_else(|err| {
+            let err = err.locate(&input);
+            eprintln!("error: {}", err.diagnostic("<stdin>"));
+            process::exit(1);
+        });
+    let day = day::<S>();
+    part.run(
+        || answer(S::part1(&input), &day, 1),
+        || answer(S::part2(&input), &day, 2),
+    );
+}
+
+/// The day `S` solves, going by its crate: `day 8` for `day8::Day8`.
+fn day<S>() -> String {
+    let krate = type_name::<S>().split("::").next().unwrap_or_default();
+    match krate.strip_prefix("day") {
+        Some(day) => format!("day {day}"),
+        None => krate.to_owned(),
+    }
+}
+
+/// Unwraps the answer to `part` of `day`, exiting with an error if there is
+/// none.
+fn answer<A>(answer: Result<A, PartError>, day: &str, part: u8) -> A {
+    answer.unwrap_or_else(|err| {
+        eprintln!("error: {day} part {part}: {err}");
+        process::exit(1);
+    })
+}
@@ -0,0 +1,107 @@
+//! Cycle detection for simulations that eventually repeat themselves.
+//!
+//! Iterating `step` from an initial state over a finite state space must
+//! revisit some state, after which everything repeats. Knowing where that
+//! happens lets a simulation skip ahead to an arbitrarily late step.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Shape of the sequence `initial, step(initial), ...`: states from index
+/// `prefix` onwards repeat every `period` steps.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest index whose state equals the state at index `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Runs the simulation until a state repeats, returning every state up to
+/// that point along with the shape of the cycle.
+fn simulate<S: Hash + Eq + Clone>(initial: S, mut step: impl FnMut(&S) -> S) -> (Vec<S>, Cycle) {
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    loop {
+        let next = step(states.last().unwrap());
+        if let Some(&prefix) = seen.get(&next) {
+            let period = states.len() - prefix;
+            return (states, Cycle { prefix, period });
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+}
+
+/// Finds where the states reached from `initial` by repeatedly applying
+/// `step` start to repeat.
+pub fn find_cycle<S: Hash + Eq + Clone>(initial: S, step: impl FnMut(&S) -> S) -> Cycle {
+    simulate(initial, step).1
+}
+
+/// The state after applying `step` `n` times, fast-forwarding through the
+/// cycle instead of simulating every step.
+pub fn nth_state<S: Hash + Eq + Clone>(initial: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (mut states, cycle) = simulate(initial, step);
+    states.swap_remove(cycle.reduce(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle_from_the_start() {
+        let cycle = find_cycle(0, |&n| (n + 1) % 3);
+        assert_eq!(
+            cycle,
+            Cycle {
+                prefix: 0,
+                period: 3
+            }
+        );
+        assert_eq!(cycle.reduce(0), 0);
+        assert_eq!(cycle.reduce(7), 1);
+        assert_eq!(nth_state(0, |&n| (n + 1) % 3, 1_000_000_000), 1);
+    }
+
+    #[test]
+    fn cycle_after_a_prefix() {
+        // 10, 5, 16, 8, 4, 2, 1, 4, ...
+        let collatz = |&n: &u64| if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+        let cycle = find_cycle(10, collatz);
+        assert_eq!(
+            cycle,
+            Cycle {
+                prefix: 4,
+                period: 3
+            }
+        );
+        assert_eq!(cycle.reduce(3), 3);
+        assert_eq!(cycle.reduce(9), 6);
+        assert_eq!(nth_state(10, collatz, 3), 8);
+        assert_eq!(nth_state(10, collatz, 1_000), 4);
+        assert_eq!(nth_state(10, collatz, 1_001), 2);
+    }
+
+    #[test]
+    fn fixed_point() {
+        let cycle = find_cycle('x', |&c| c);
+        assert_eq!(
+            cycle,
+            Cycle {
+                prefix: 0,
+                period: 1
+            }
+        );
+        assert_eq!(nth_state('x', |&c| c, usize::MAX), 'x');
+    }
+}
@@ -0,0 +1,148 @@
+//! Regression checks against the puzzle examples shipped with each day.
+//!
+//! Every `test_input*` file in a day's directory is paired with a
+//! `<name>.expected` sidecar holding the known answers, one per line:
+//!
+//! ```text
+//! part1: 8
+//! part2: 2286
+//! ```
+//!
+//! Parts missing from the sidecar are not run, for examples that only
+//! exercise one half of a puzzle. Examples that use different constants than
+//! the real puzzle set them with `param: <key>=<value>` lines.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    params::{self, Params},
+    Solution,
+};
+
+const PREFIX: &str = "test_input";
+const SIDECAR_EXTENSION: &str = "expected";
+
+/// Known answers of one example input.
+#[derive(Debug, Default)]
+struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+    params: Params,
+}
+
+fn read_expected(path: &Path) -> Result<Expected, String> {
+    let text =
+        fs::read_to_string(path).map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .try_fold(Expected::default(), |mut expected, line| {
+            match line.split_once(':').map(|(k, v)| (k.trim(), v.trim())) {
+                Some(("part1", answer)) => expected.part1 = Some(answer.to_owned()),
+                Some(("part2", answer)) => expected.part2 = Some(answer.to_owned()),
+                Some(("param", assignment)) => {
+                    let (key, value) = params::parse_assignment(assignment)
+                        .map_err(|err| format!("{}: {err}", path.display()))?;
+                    expected.params.set(key, value);
+                }
+                _ => {
+                    return Err(format!(
+                        "{}: expected `part1: <answer>`, `part2: <answer>` or `param: <key>=<value>`, found `{line}`",
+                        path.display()
+                    ))
+                }
+            }
+            Ok(expected)
+        })
+}
+
+/// Lists the example inputs in `dir`, sorted by name.
+pub fn discover(dir: &Path) -> Vec<PathBuf> {
+    let mut inputs: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| {
+                    path.is_file()
+                        && path.extension().is_none_or(|ext| ext != SIDECAR_EXTENSION)
+                        && path
+                            .file_name()
+                            .and_then(|name| name.to_str())
+                            .is_some_and(|name| name.starts_with(PREFIX))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    inputs.sort();
+    inputs
+}
+
+fn mismatch(path: &Path, part: u8, expected: &str, actual: &str) -> String {
+    format!(
+        "{} part {part}:\n  expected: {expected}\n    actual: {actual}",
+        path.display()
+    )
+}
+
+/// Runs the parts of `S` with known answers on one example, returning every mismatch.
+fn check_example<S: Solution>(path: &Path) -> Vec<String> {
+    let expected = match read_expected(&path.with_extension(SIDECAR_EXTENSION)) {
+        Ok(expected) => expected,
+        Err(err) => return vec![err],
+    };
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) => return vec![format!("cannot read {}: {err}", path.display())],
+    };
+    let input = match expected
+        .params
+        .consumed_by(|params| S::parse_with(&text, params))
+    {
+        Ok(input) => input,
+        Err(err) => return vec![err.locate(&text).diagnostic(&path.display().to_string())],
+    };
+    let mut failures = vec![];
+    if let Some(expected) = expected.part1 {
+        let actual = match S::part1(&input) {
+            Ok(answer) => answer.to_string(),
+            Err(err) => err.to_string(),
+        };
+        if actual != expected {
+            failures.push(mismatch(path, 1, &expected, &actual));
+        }
+    }
+    if let Some(expected) = expected.part2 {
+        let actual = match S::part2(&input) {
+            Ok(answer) => answer.to_string(),
+            Err(err) => err.to_string(),
+        };
+        if actual != expected {
+            failures.push(mismatch(path, 2, &expected, &actual));
+        }
+    }
+    failures
+}
+
+/// Checks `S` against every example in `dir`, panicking with a diff of the wrong answers.
+pub fn check<S: Solution>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let inputs = discover(dir);
+    assert!(
+        !inputs.is_empty(),
+        "no {PREFIX}* files in {}",
+        dir.display()
+    );
+    let failures: Vec<String> = inputs
+        .iter()
+        .flat_map(|path| check_example::<S>(path))
+        .collect();
+    if !failures.is_empty() {
+        panic!(
+            "{} example check(s) failed:\n\n{}\n",
+            failures.len(),
+            failures.join("\n\n")
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Seeded generators of random puzzle inputs, for stress and property tests.
+//!
+//! Days with a generator expose it as `generate::GENERATOR`. Generated inputs
+//! follow the puzzle's format and the promises its text makes, so they parse
+//! and solve like real ones, but at any size and with the corner cases real
+//! inputs happen to avoid. The same seed always gives the same input.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+pub use rand::{
+    rngs::StdRng,
+    seq::{IndexedRandom, SliceRandom},
+    RngExt, SeedableRng,
+};
+
+use crate::{geometry::Pos, Answer};
+
+/// Answers parts 1 and 2 of a generated input, where known.
+pub type AnswersFn = fn(&mut StdRng, usize) -> [Option<Answer>; 2];
+
+/// Writes inputs of one day.
+#[derive(Clone, Copy)]
+pub struct Generator {
+    /// Size of the real input, in the unit `input` takes: the side of a grid,
+    /// a number of lines and so on, as documented by each day.
+    pub size: usize,
+    /// Writes an input of the given size.
+    pub input: fn(&mut StdRng, usize) -> String,
+    /// The answers to parts 1 and 2 that `input` builds into the input of
+    /// the same seed and size, for generators that plant them.
+    pub answers: Option<AnswersFn>,
+}
+
+impl Generator {
+    /// The input of `size` generated from `seed`.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.input)(&mut StdRng::seed_from_u64(seed), size)
+    }
+
+    /// The answers planted in the input of `size` generated from `seed`, as
+    /// far as the generator knows them.
+    pub fn answers(&self, seed: u64, size: usize) -> [Option<Answer>; 2] {
+        self.answers.map_or([None, None], |answers| {
+            answers(&mut StdRng::seed_from_u64(seed), size)
+        })
+    }
+}
+
+/// A word of `len` random lowercase letters.
+pub fn word(rng: &mut StdRng, len: usize) -> String {
+    (0..len)
+        .map(|_| char::from(rng.random_range(b'a'..=b'z')))
+        .collect()
+}
+
+/// `n` different words of `len` random lowercase letters, none of them in
+/// `reserved`.
+///
+/// Panics if there are not enough such words.
+pub fn distinct_words(rng: &mut StdRng, n: usize, len: usize, reserved: &[&str]) -> Vec<String> {
+    let available = 26usize.saturating_pow(len as u32) - reserved.len();
+    assert!(n <= available, "cannot pick {n} words of {len} letters");
+    let mut seen: HashSet<String> = reserved.iter().map(|&word| word.to_owned()).collect();
+    let mut words = Vec::with_capacity(n);
+    while words.len() < n {
+        let word = word(rng, len);
+        if seen.insert(word.clone()) {
+            words.push(word);
+        }
+    }
+    words
+}
+
+/// A grid of `height` rows of `width` cells, one line per row.
+pub fn grid(width: usize, height: usize, mut cell: impl FnMut(Pos) -> char) -> String {
+    let mut s = String::with_capacity((width + 1) * height);
+    for r in 0..height {
+        for c in 0..width {
+            s.push(cell(Pos::new(r as isize, c as isize)));
+        }
+        s.push('\n');
+    }
+    s
+}
+
+/// The squares around a square, clockwise from the one above. Squares
+/// sharing an edge have even indices.
+const RING: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// Whether adding `square` to `shape` keeps its outline a single simple
+/// loop: the squares around it that are in the shape must form one
+/// unbroken run that includes a square sharing an edge with it.
+fn keeps_simple(shape: &BTreeSet<Pos>, square: Pos) -> bool {
+    let ring = RING.map(|(r, c)| shape.contains(&(square + Pos::new(r, c))));
+    let runs = (0..8).filter(|&i| ring[i] && !ring[(i + 7) % 8]).count();
+    let touches = (0..8).step_by(2).any(|i| ring[i]);
+    runs == 1 && touches
+}
+
+/// A random simple closed loop through the points of a `height`×`width`
+/// grid, taking unit steps, as its points in order. The loop is the outline
+/// of a random shape covering about `fill` of the grid, which may touch
+/// any of the grid's edges.
+///
+/// Panics if the grid is smaller than 2×2.
+pub fn random_loop(rng: &mut StdRng, height: usize, width: usize, fill: f64) -> Vec<Pos> {
+    assert!(height >= 2 && width >= 2, "a loop needs a 2×2 grid");
+    // Squares between the grid points, named by their top-left corner.
+    let (rows, cols) = (height - 1, width - 1);
+    let target = ((rows * cols) as f64 * fill).max(1.0) as usize;
+    let first = Pos::new(
+        rng.random_range(0..rows) as isize,
+        rng.random_range(0..cols) as isize,
+    );
+    let (rows, cols) = (rows as isize, cols as isize);
+    let mut shape = BTreeSet::from([first]);
+    let mut frontier: Vec<Pos> = first.neighbours4().collect();
+    while shape.len() < target && !frontier.is_empty() {
+        let square = frontier.swap_remove(rng.random_range(0..frontier.len()));
+        let inside = (0..rows).contains(&square.r) && (0..cols).contains(&square.c);
+        if !inside || shape.contains(&square) || !keeps_simple(&shape, square) {
+            continue;
+        }
+        // Mostly grow branches rather than fill in between them, for a long
+        // and winding outline.
+        let touching = square
+            .neighbours4()
+            .filter(|next| shape.contains(next))
+            .count();
+        if touching == 1 || rng.random_bool(0.2) {
+            shape.insert(square);
+            frontier.extend(square.neighbours4());
+        }
+    }
+    // Edges of the outline, which bound exactly one square of the shape.
+    let mut edges: BTreeMap<Pos, Vec<Pos>> = BTreeMap::new();
+    for &square in &shape {
+        let corners = [(0, 0), (0, 1), (1, 1), (1, 0)].map(|(r, c)| square + Pos::new(r, c));
+        for (i, side) in [(-1, 0), (0, 1), (1, 0), (0, -1)].into_iter().enumerate() {
+            if !shape.contains(&(square + Pos::new(side.0, side.1))) {
+                let (a, b) = (corners[i], corners[(i + 1) % 4]);
+                edges.entry(a).or_default().push(b);
+                edges.entry(b).or_default().push(a);
+            }
+        }
+    }
+    let start = *edges.keys().next().unwrap();
+    let mut points = vec![start];
+    let mut prev = start;
+    let mut curr = edges[&start][0];
+    while curr != start {
+        points.push(curr);
+        let next = edges[&curr].iter().copied().find(|&p| p != prev).unwrap();
+        (prev, curr) = (curr, next);
+    }
+    points
+}
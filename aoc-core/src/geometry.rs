@@ -0,0 +1,336 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use crate::{parse, ParseError};
+
+/// A (row, column) coordinate on a 2D grid. Also used for direction vectors.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct Pos {
+    pub r: isize,
+    pub c: isize,
+}
+
+pub const UP: Pos = Dir::Up.delta();
+pub const DOWN: Pos = Dir::Down.delta();
+pub const LEFT: Pos = Dir::Left.delta();
+pub const RIGHT: Pos = Dir::Right.delta();
+
+impl Pos {
+    pub const fn new(r: isize, c: isize) -> Self {
+        Pos { r, c }
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.r.abs_diff(other.r) + self.c.abs_diff(other.c)
+    }
+
+    /// This vector turned a quarter clockwise, e.g. `RIGHT` becomes `DOWN`.
+    pub fn clockwise(self) -> Pos {
+        Pos {
+            r: self.c,
+            c: -self.r,
+        }
+    }
+
+    /// This vector turned a quarter counterclockwise, e.g. `RIGHT` becomes `UP`.
+    pub fn counterclockwise(self) -> Pos {
+        Pos {
+            r: -self.c,
+            c: self.r,
+        }
+    }
+
+    /// The positions above, right of, below and left of this one.
+    pub fn neighbours4(self) -> impl Iterator<Item = Pos> {
+        Dir::ALL.into_iter().map(move |dir| self + dir)
+    }
+
+    /// The eight positions around this one, row by row.
+    pub fn neighbours8(self) -> impl Iterator<Item = Pos> {
+        (-1..=1)
+            .flat_map(|r| (-1..=1).map(move |c| Pos { r, c }))
+            .filter(|&delta| delta != Pos::default())
+            .map(move |delta| self + delta)
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+    fn add(self, rhs: Self) -> Self::Output {
+        Pos {
+            r: self.r + rhs.r,
+            c: self.c + rhs.c,
+        }
+    }
+}
+
+impl Add<Dir> for Pos {
+    type Output = Pos;
+    fn add(self, rhs: Dir) -> Self::Output {
+        self + rhs.delta()
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Dir> for Pos {
+    fn add_assign(&mut self, rhs: Dir) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Pos {
+            r: self.r - rhs.r,
+            c: self.c - rhs.c,
+        }
+    }
+}
+
+impl Neg for Pos {
+    type Output = Pos;
+    fn neg(self) -> Self::Output {
+        Pos {
+            r: -self.r,
+            c: -self.c,
+        }
+    }
+}
+
+impl Mul<isize> for Pos {
+    type Output = Pos;
+    fn mul(self, rhs: isize) -> Self::Output {
+        Pos {
+            r: self.r * rhs,
+            c: self.c * rhs,
+        }
+    }
+}
+
+impl TryFrom<(usize, usize)> for Pos {
+    type Error = TryFromIntError;
+    fn try_from((r, c): (usize, usize)) -> Result<Self, Self::Error> {
+        Ok(Pos {
+            r: r.try_into()?,
+            c: c.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Pos> for (usize, usize) {
+    type Error = TryFromIntError;
+    fn try_from(pos: Pos) -> Result<Self, Self::Error> {
+        Ok((pos.r.try_into()?, pos.c.try_into()?))
+    }
+}
+
+/// One of the four grid directions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// All directions, clockwise from `Up`.
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// The unit vector pointing this way.
+    pub const fn delta(self) -> Pos {
+        match self {
+            Dir::Up => Pos { r: -1, c: 0 },
+            Dir::Right => Pos { r: 0, c: 1 },
+            Dir::Down => Pos { r: 1, c: 0 },
+            Dir::Left => Pos { r: 0, c: -1 },
+        }
+    }
+
+    pub fn turn_right(self) -> Dir {
+        Dir::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir {
+        Dir::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Dir {
+        Dir::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl From<Dir> for Pos {
+    fn from(dir: Dir) -> Self {
+        dir.delta()
+    }
+}
+
+impl Mul<isize> for Dir {
+    type Output = Pos;
+    fn mul(self, rhs: isize) -> Self::Output {
+        self.delta() * rhs
+    }
+}
+
+/// A vector that is not one of the four unit steps, so it has no [`Dir`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct NotADirection(pub Pos);
+
+impl Display for NotADirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a unit step", self.0)
+    }
+}
+
+impl Error for NotADirection {}
+
+impl TryFrom<Pos> for Dir {
+    type Error = NotADirection;
+    fn try_from(pos: Pos) -> Result<Self, Self::Error> {
+        Dir::ALL
+            .into_iter()
+            .find(|dir| dir.delta() == pos)
+            .ok_or(NotADirection(pos))
+    }
+}
+
+/// A point or vector in 3D space.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Point3<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Point3<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point3<T> {
+    type Output = Point3<T>;
+    fn mul(self, rhs: T) -> Self::Output {
+        Point3 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl Point3<i64> {
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+}
+
+impl<T: FromStr> FromStr for Point3<T> {
+    type Err = ParseError;
+
+    /// Parses `x,y,z`, allowing spaces around the numbers.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords = s
+            .split(',')
+            .map(|n| parse::number(n.trim()))
+            .collect::<Result<Vec<T>, _>>()?;
+        let [x, y, z] =
+            <[T; 3]>::try_from(coords).map_err(|_| ParseError::expected("three coordinates", s))?;
+        Ok(Point3 { x, y, z })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning_round_comes_back() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.reverse().reverse(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.delta().clockwise(), dir.turn_right().delta());
+            assert_eq!(dir.delta().counterclockwise(), dir.turn_left().delta());
+            assert_eq!(-dir.delta(), dir.reverse().delta());
+        }
+        assert_eq!(RIGHT.clockwise(), DOWN);
+        assert_eq!(RIGHT.counterclockwise(), UP);
+    }
+
+    #[test]
+    fn vectors_convert_to_directions() {
+        for dir in Dir::ALL {
+            assert_eq!(Dir::try_from(dir.delta()), Ok(dir));
+        }
+        assert_eq!(
+            Dir::try_from(Pos::new(1, 1)),
+            Err(NotADirection(Pos::new(1, 1)))
+        );
+        assert!(Dir::try_from(Pos::default()).is_err());
+    }
+
+    #[test]
+    fn neighbourhoods() {
+        let pos = Pos::new(-3, 5);
+        let four: Vec<Pos> = pos.neighbours4().collect();
+        assert_eq!(
+            four,
+            [
+                Pos::new(-4, 5),
+                Pos::new(-3, 6),
+                Pos::new(-2, 5),
+                Pos::new(-3, 4)
+            ]
+        );
+        let eight: Vec<Pos> = pos.neighbours8().collect();
+        assert_eq!(eight.len(), 8);
+        assert!(!eight.contains(&pos));
+        assert!(four.iter().all(|p| eight.contains(p)));
+        assert!(eight.iter().all(|&p| p.manhattan(pos) <= 2));
+    }
+
+    #[test]
+    fn index_conversions_reject_negatives() {
+        assert_eq!(Pos::try_from((2, 3)), Ok(Pos::new(2, 3)));
+        assert!(<(usize, usize)>::try_from(Pos::new(-1, 0)).is_err());
+        assert!(Pos::try_from((usize::MAX, 0)).is_err());
+    }
+
+    #[test]
+    fn parses_points() {
+        let p: Point3<i64> = " 1, -2 ,3".parse().unwrap();
+        assert_eq!(p, Point3 { x: 1, y: -2, z: 3 });
+        assert_eq!(p.manhattan(Point3::default()), 6);
+        assert!("1,2".parse::<Point3<i64>>().is_err());
+        assert!("1,2,x".parse::<Point3<i64>>().is_err());
+    }
+}
@@ -0,0 +1,312 @@
+//! Searches over explicit and implicit graphs.
+//!
+//! Anything that can list the edges leaving a vertex is a [`Graph`]: an
+//! adjacency map, or a closure wrapped in [`FnGraph`] for graphs that are too
+//! large to build up front, such as states on an infinite grid.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::{BuildHasher, Hash},
+    ops::Add,
+};
+
+use crate::{geometry::Pos, grid::Grid};
+
+/// A directed graph with vertices `V` and edge weights `W`.
+pub trait Graph<V, W> {
+    /// The edges leaving `v`, as (target, weight) pairs.
+    fn edges(&self, v: &V) -> impl Iterator<Item = (V, W)>;
+}
+
+impl<V, W, S> Graph<V, W> for HashMap<V, Vec<(V, W)>, S>
+where
+    V: Clone + Eq + Hash,
+    W: Clone,
+    S: BuildHasher,
+{
+    fn edges(&self, v: &V) -> impl Iterator<Item = (V, W)> {
+        self.get(v).into_iter().flatten().cloned()
+    }
+}
+
+/// A graph whose edges are computed on demand by a closure.
+pub struct FnGraph<F>(pub F);
+
+impl<V, W, F, I> Graph<V, W> for FnGraph<F>
+where
+    F: Fn(&V) -> I,
+    I: IntoIterator<Item = (V, W)>,
+{
+    fn edges(&self, v: &V) -> impl Iterator<Item = (V, W)> {
+        (self.0)(v).into_iter()
+    }
+}
+
+/// Number of steps from `start` to every vertex reachable in at most
+/// `max_depth` steps. Weights are ignored.
+pub fn bfs_within<V, W>(graph: &impl Graph<V, W>, start: V, max_depth: usize) -> HashMap<V, usize>
+where
+    V: Clone + Eq + Hash,
+{
+    let mut depths = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((v, depth)) = queue.pop_front() {
+        if depth == max_depth {
+            continue;
+        }
+        for (next, _) in graph.edges(&v) {
+            if let Entry::Vacant(entry) = depths.entry(next.clone()) {
+                entry.insert(depth + 1);
+                queue.push_back((next, depth + 1));
+            }
+        }
+    }
+    depths
+}
+
+/// Number of steps from `start` to every reachable vertex. Weights are ignored.
+pub fn bfs<V, W>(graph: &impl Graph<V, W>, start: V) -> HashMap<V, usize>
+where
+    V: Clone + Eq + Hash,
+{
+    bfs_within(graph, start, usize::MAX)
+}
+
+/// A cheapest path found by [`dijkstra`] or [`astar`], from start to goal.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Path<V, W> {
+    pub cost: W,
+    pub vertices: Vec<V>,
+}
+
+/// Best known cost of each vertex reached, with the vertex it was reached from.
+type Predecessors<V, W> = HashMap<V, (W, Option<V>)>;
+
+/// Best-first search from `start` until a vertex satisfying `is_goal` is
+/// settled. Returns everything reached along with that goal, if found.
+fn search<V, W>(
+    graph: &impl Graph<V, W>,
+    start: V,
+    mut is_goal: impl FnMut(&V) -> bool,
+    mut heuristic: impl FnMut(&V) -> W,
+) -> (Predecessors<V, W>, Option<V>)
+where
+    V: Clone + Eq + Hash,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let mut best = HashMap::from([(start.clone(), (W::default(), None))]);
+    // The heap holds indices into `queued` so that `V` need not be ordered.
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), W::default(), 0))]);
+    let mut queued = vec![start];
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        let v = queued[i].clone();
+        if best[&v].0 < cost {
+            continue;
+        }
+        if is_goal(&v) {
+            return (best, Some(v));
+        }
+        for (next, weight) in graph.edges(&v) {
+            let next_cost = cost + weight;
+            if best.get(&next).is_none_or(|&(known, _)| next_cost < known) {
+                best.insert(next.clone(), (next_cost, Some(v.clone())));
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    queued.len(),
+                )));
+                queued.push(next);
+            }
+        }
+    }
+    (best, None)
+}
+
+/// Cheapest path from `start` to a vertex satisfying `is_goal`, guided by a
+/// `heuristic` that must never overestimate the remaining cost.
+pub fn astar<V, W>(
+    graph: &impl Graph<V, W>,
+    start: V,
+    is_goal: impl FnMut(&V) -> bool,
+    heuristic: impl FnMut(&V) -> W,
+) -> Option<Path<V, W>>
+where
+    V: Clone + Eq + Hash,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let (best, goal) = search(graph, start, is_goal, heuristic);
+    let goal = goal?;
+    let cost = best[&goal].0;
+    let mut vertices = vec![goal];
+    while let Some((_, Some(prev))) = best.get(vertices.last().unwrap()) {
+        vertices.push(prev.clone());
+    }
+    vertices.reverse();
+    Some(Path { cost, vertices })
+}
+
+/// Cheapest path from `start` to a vertex satisfying `is_goal`.
+pub fn dijkstra<V, W>(
+    graph: &impl Graph<V, W>,
+    start: V,
+    is_goal: impl FnMut(&V) -> bool,
+) -> Option<Path<V, W>>
+where
+    V: Clone + Eq + Hash,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    astar(graph, start, is_goal, |_| W::default())
+}
+
+/// Cost of the cheapest path from `start` to every reachable vertex.
+pub fn distances<V, W>(graph: &impl Graph<V, W>, start: V) -> HashMap<V, W>
+where
+    V: Clone + Eq + Hash,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let (best, _) = search(graph, start, |_| false, |_| W::default());
+    best.into_iter().map(|(v, (cost, _))| (v, cost)).collect()
+}
+
+/// Splits the vertices reachable from `vertices` into connected components.
+/// Edges are assumed to go both ways.
+pub fn connected_components<V, W>(
+    graph: &impl Graph<V, W>,
+    vertices: impl IntoIterator<Item = V>,
+) -> Vec<Vec<V>>
+where
+    V: Clone + Eq + Hash,
+{
+    let mut seen = HashSet::new();
+    let mut components = vec![];
+    for v in vertices {
+        if seen.contains(&v) {
+            continue;
+        }
+        let component: Vec<V> = bfs(graph, v).into_keys().collect();
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+    components
+}
+
+/// Compresses a maze into a graph of its junctions.
+///
+/// Every `passable` cell that does not have exactly two passable neighbours
+/// (junctions, dead ends and the entrances on the border) becomes a vertex,
+/// and each corridor between two of them becomes an edge weighted by its
+/// length in steps.
+pub fn contract_corridors<T>(
+    grid: &Grid<T>,
+    passable: impl Fn(&T) -> bool,
+) -> HashMap<Pos, Vec<(Pos, usize)>> {
+    let open_neighbours = |pos: Pos| {
+        grid.neighbours4(pos)
+            .filter(|&next| passable(&grid[next]))
+            .collect::<Vec<_>>()
+    };
+    let vertices: HashSet<Pos> = grid
+        .iter()
+        .filter(|&(pos, cell)| passable(cell) && open_neighbours(pos).len() != 2)
+        .map(|(pos, _)| pos)
+        .collect();
+    vertices
+        .iter()
+        .map(|&v| {
+            let edges = open_neighbours(v)
+                .into_iter()
+                .map(|first| {
+                    let (mut prev, mut curr, mut length) = (v, first, 1);
+                    while !vertices.contains(&curr) {
+                        let next = open_neighbours(curr)
+                            .into_iter()
+                            .find(|&next| next != prev)
+                            .expect("corridor cells have two open neighbours");
+                        (prev, curr, length) = (curr, next, length + 1);
+                    }
+                    (curr, length)
+                })
+                .collect();
+            (v, edges)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -1-> b -1-> c, a -5-> c, and d on its own.
+    fn weighted() -> HashMap<char, Vec<(char, u32)>> {
+        HashMap::from([
+            ('a', vec![('b', 1), ('c', 5)]),
+            ('b', vec![('c', 1)]),
+            ('c', vec![]),
+            ('d', vec![('a', 1)]),
+        ])
+    }
+
+    #[test]
+    fn cheapest_path_beats_fewest_edges() {
+        let path = dijkstra(&weighted(), 'a', |&v| v == 'c').unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.vertices, ['a', 'b', 'c']);
+        assert_eq!(
+            distances(&weighted(), 'a'),
+            HashMap::from([('a', 0), ('b', 1), ('c', 2)])
+        );
+    }
+
+    #[test]
+    fn unreachable_targets_have_no_path() {
+        assert_eq!(dijkstra(&weighted(), 'a', |&v| v == 'd'), None);
+        assert_eq!(dijkstra(&weighted(), 'z', |&v| v == 'a'), None);
+        assert_eq!(astar(&weighted(), 'c', |&v| v == 'a', |_| 0), None);
+    }
+
+    #[test]
+    fn starting_on_the_goal() {
+        let path = dijkstra(&weighted(), 'b', |&v| v == 'b').unwrap();
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.vertices, ['b']);
+    }
+
+    #[test]
+    fn astar_on_an_open_grid() {
+        let grid = FnGraph(|p: &Pos| p.neighbours4().map(|next| (next, 1)).collect::<Vec<_>>());
+        let goal = Pos::new(3, -4);
+        let path = astar(&grid, Pos::default(), |&p| p == goal, |p| p.manhattan(goal)).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.vertices.len(), 8);
+        assert!(path.vertices.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+    }
+
+    #[test]
+    fn bfs_stops_at_the_depth_limit() {
+        let line = FnGraph(|&n: &i32| [(n - 1, ()), (n + 1, ())]);
+        let depths = bfs_within(&line, 0, 2);
+        assert_eq!(depths.len(), 5);
+        assert_eq!(depths[&-2], 2);
+        assert!(!depths.contains_key(&3));
+    }
+
+    #[test]
+    fn components() {
+        let graph = HashMap::from([(1, vec![(2, ())]), (2, vec![(1, ())]), (3, vec![])]);
+        let mut components = connected_components(&graph, [1, 2, 3]);
+        components.iter_mut().for_each(|component| component.sort());
+        components.sort();
+        assert_eq!(components, [vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn corridors_become_weighted_edges() {
+        let maze: Grid<char> = "#.###\n#...#\n###.#".parse().unwrap();
+        let graph = contract_corridors(&maze, |&cell| cell == '.');
+        let (entrance, exit) = (Pos::new(0, 1), Pos::new(2, 3));
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph[&entrance], [(exit, 4)]);
+        assert_eq!(graph[&exit], [(entrance, 4)]);
+    }
+}
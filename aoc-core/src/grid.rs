@@ -0,0 +1,358 @@
+//! A rectangular grid with flat, row-major storage, indexed by [`Pos`].
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{geometry::Pos, ParseError};
+
+/// Positions of a `width` x `height` grid, row by row.
+fn row_major(width: usize, height: usize) -> impl Iterator<Item = Pos> {
+    (0..height as isize).flat_map(move |r| (0..width as isize).map(move |c| Pos { r, c }))
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    ///
+    /// Panics if there are not exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "cells do not fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a grid with one row per line, turning each character into a
+    /// cell with `cell`. `cell` also gets the character's slice of the line,
+    /// to point its errors at. Every line must have as many characters as
+    /// the first, and there must be at least one.
+    pub fn from_lines(
+        lines: &[&str],
+        mut cell: impl FnMut(char, &str) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let Some(first) = lines.first() else {
+            return Err(ParseError::new("expected a row of cells, found no lines"));
+        };
+        let width = first.chars().count();
+        if width == 0 {
+            return Err(ParseError::expected("a row of cells", first));
+        }
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in lines {
+            if line.chars().count() != width {
+                return Err(ParseError::expected(
+                    format!("a row of {width} cells"),
+                    line,
+                ));
+            }
+            for (i, ch) in line.char_indices() {
+                cells.push(cell(ch, &line[i..i + ch.len_utf8()])?);
+            }
+        }
+        Ok(Grid::new(width, lines.len(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (0..self.height as isize).contains(&pos.r) && (0..self.width as isize).contains(&pos.c)
+    }
+
+    fn offset(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.r as usize * self.width + pos.c as usize)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// The cell at `pos` on the grid repeated infinitely in every direction.
+    pub fn get_wrapping(&self, pos: Pos) -> &T {
+        let r = pos.r.rem_euclid(self.height as isize) as usize;
+        let c = pos.c.rem_euclid(self.width as isize) as usize;
+        &self.cells[r * self.width + c]
+    }
+
+    pub fn swap(&mut self, a: Pos, b: Pos) {
+        let (a, b) = (self.index_of(a), self.index_of(b));
+        self.cells.swap(a, b);
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        row_major(self.width, self.height)
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, row by row, that satisfies `pred`.
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, cell)| pred(cell).then_some(pos))
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|r| self.row(r))
+    }
+
+    pub fn column(&self, c: usize) -> impl Iterator<Item = &T> {
+        assert!(c < self.width, "column {c} out of bounds");
+        self.cells.iter().skip(c).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|c| self.column(c))
+    }
+
+    /// The in-bounds positions above, right of, below and left of `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours4().filter(|&p| self.contains(p))
+    }
+
+    /// The in-bounds positions around `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours8().filter(|&p| self.contains(p))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Builds a `width` x `height` grid whose cell at `pos` is `self[source(pos)]`.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = row_major(width, height)
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |Pos { r, c }| Pos { r: c, c: r })
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_row = self.height as isize - 1;
+        self.remap(self.height, self.width, |Pos { r, c }| Pos {
+            r: last_row - c,
+            c: r,
+        })
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_col = self.width as isize - 1;
+        self.remap(self.height, self.width, |Pos { r, c }| Pos {
+            r: c,
+            c: last_col - r,
+        })
+    }
+
+    fn index_of(&self, pos: Pos) -> usize {
+        self.offset(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the {}x{} grid", self.height, self.width))
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    /// The cell at `pos`, or `default` outside the grid.
+    pub fn get_or(&self, pos: Pos, default: T) -> T {
+        self.get(pos).copied().unwrap_or(default)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let i = self.index_of(pos);
+        &mut self.cells[i]
+    }
+}
+
+impl Grid<char> {
+    /// Parses like `from_str`, but rejects any character not in `tiles`.
+    pub fn of_tiles(s: &str, tiles: &str) -> Result<Self, ParseError> {
+        let expected: Vec<String> = tiles.chars().map(|tile| format!("`{tile}`")).collect();
+        let expected = format!("one of {}", expected.join(", "));
+        Grid::from_lines(&rows(s)?, |ch, text| {
+            if tiles.contains(ch) {
+                Ok(ch)
+            } else {
+                Err(ParseError::expected(expected.as_str(), text))
+            }
+        })
+    }
+}
+
+/// The lines of `s` without its trailing blank lines, at least one.
+fn rows(s: &str) -> Result<Vec<&str>, ParseError> {
+    let lines: Vec<&str> = s.trim_end_matches(['\n', '\r']).lines().collect();
+    if lines.is_empty() {
+        return Err(ParseError::expected("a row of cells", &s[s.len()..]));
+    }
+    Ok(lines)
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    /// Parses one row per line. Trailing blank lines are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::from_lines(&rows(s)?, |ch, _| Ok(ch))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (r, row) in self.rows().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn access_outside_the_grid() {
+        let g = grid("ab\ncd\nef");
+        assert_eq!(g.get(Pos { r: 2, c: 1 }), Some(&'f'));
+        for pos in [
+            Pos { r: -1, c: 0 },
+            Pos { r: 0, c: -1 },
+            Pos { r: 3, c: 0 },
+            Pos { r: 0, c: 2 },
+        ] {
+            assert_eq!(g.get(pos), None, "{pos:?}");
+            assert!(!g.contains(pos));
+            assert_eq!(g.get_or(pos, '#'), '#');
+        }
+        assert_eq!(*g.get_wrapping(Pos { r: -1, c: 2 }), 'e');
+        assert_eq!(*g.get_wrapping(Pos { r: 7, c: -3 }), 'd');
+    }
+
+    #[test]
+    fn unknown_tiles() {
+        let g = Grid::of_tiles("#.\n.#\n", ".#").unwrap();
+        assert_eq!(g, grid("#.\n.#"));
+        let err = Grid::of_tiles("#.\n.x\n", ".#").unwrap_err();
+        assert_eq!(err.to_string(), "expected one of `.`, `#`, found `x`");
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the 3x2 grid")]
+    fn indexing_outside_the_grid_panics() {
+        let _ = grid("ab\ncd\nef")[Pos { r: 0, c: 2 }];
+    }
+
+    #[test]
+    fn rotations() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.rotate_cw(), grid("da\neb\nfc"));
+        assert_eq!(g.rotate_ccw(), grid("cf\nbe\nad"));
+        assert_eq!(g.transpose(), grid("ad\nbe\ncf"));
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+        assert_eq!(g.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), g);
+    }
+
+    #[test]
+    fn neighbourhoods_stay_inside() {
+        let g = grid("abc\ndef\nghi");
+        let corner = Pos { r: 0, c: 0 };
+        let centre = Pos { r: 1, c: 1 };
+        assert_eq!(g.neighbours4(corner).count(), 2);
+        assert_eq!(g.neighbours8(corner).count(), 3);
+        assert_eq!(g.neighbours4(centre).count(), 4);
+        assert_eq!(g.neighbours8(centre).count(), 8);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.row(1), ['d', 'e', 'f']);
+        assert_eq!(g.column(2).collect::<String>(), "cf");
+        assert_eq!(g.position(|&ch| ch == 'e'), Some(Pos { r: 1, c: 1 }));
+    }
+
+    #[test]
+    fn ragged_rows_point_at_the_row() {
+        let input = "abc\nde\nfgh";
+        let err = input.parse::<Grid<char>>().unwrap_err().locate(input);
+        assert_eq!(err.message, "expected a row of 3 cells, found `de`");
+        let location = err.location.unwrap();
+        assert_eq!((location.line, location.column), (2, 1));
+    }
+
+    #[test]
+    fn empty_grids_are_rejected() {
+        assert!("".parse::<Grid<char>>().is_err());
+        assert!("\n\n".parse::<Grid<char>>().is_err());
+    }
+}
@@ -0,0 +1,25 @@
+use std::{io::BufRead, str::FromStr};
+
+use crate::ParseError;
+
+pub type Row = Vec<char>;
+
+/// Collects the lines of `s` into a matrix of characters, one row per line.
+pub fn char_rows(s: &str) -> Vec<Row> {
+    s.lines()
+        .map(|line| line.chars().collect::<Row>())
+        .collect()
+}
+
+/// Parsing from any buffered reader for inputs that implement `FromStr`.
+pub trait FromReader: FromStr<Err = ParseError> {
+    fn from_reader<R: BufRead>(mut reader: R) -> Result<Self, ParseError> {
+        let mut s = String::new();
+        reader
+            .read_to_string(&mut s)
+            .map_err(|err| ParseError::new(format!("cannot read input: {err}")))?;
+        s.parse()
+    }
+}
+
+impl<T: FromStr<Err = ParseError>> FromReader for T {}
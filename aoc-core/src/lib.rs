@@ -0,0 +1,20 @@
+//! Helpers shared by the per-day puzzle crates.
+
+pub mod checked;
+pub mod cli;
+pub mod cycle;
+pub mod examples;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod params;
+pub mod parse;
+pub mod polygon;
+pub mod solution;
+
+pub use checked::Overflow;
+pub use parse::ParseError;
+pub use solution::{Answer, PartError, Solution};
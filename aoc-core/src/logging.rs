@@ -0,0 +1,21 @@
+//! Solver diagnostics, written to stderr so stdout only carries answers.
+
+use log::LevelFilter;
+
+/// Sends `log` records to stderr.
+///
+/// `verbosity` counts `-v` flags: none shows warnings, one adds debug and
+/// two add trace messages. `RUST_LOG` filters, e.g. `RUST_LOG=day5=trace`,
+/// are applied on top.
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .format_timestamp(None)
+        .init();
+}
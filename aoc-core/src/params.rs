@@ -0,0 +1,100 @@
+//! Tunable puzzle constants.
+//!
+//! Some puzzles are answered for a fixed number of steps, a test area and
+//! the like, and their examples use different values than the real input.
+//! Days read those constants from [`Params`], falling back to the puzzle's
+//! own values for anything not set. A key that no day asks for is an
+//! error rather than silently leaving the default in place.
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
+
+use crate::ParseError;
+
+/// Parameter values by name, still as text until a day asks for them.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+    /// Keys asked for through [`Params::get`], set or not.
+    read: RefCell<BTreeSet<String>>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(key.into(), value.into());
+    }
+
+    /// The value of `key`, or `default` when it is not set.
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> Result<T, ParseError> {
+        self.read.borrow_mut().insert(key.to_owned());
+        match self.values.get(key) {
+            None => Ok(default),
+            Some(value) => value.parse().map_err(|_| {
+                ParseError::new(format!("invalid value `{value}` for parameter `{key}`"))
+            }),
+        }
+    }
+
+    /// Runs `parse` on these parameters, then fails on any key it did not
+    /// ask for, naming the ones it did.
+    pub fn consumed_by<T>(
+        &self,
+        parse: impl FnOnce(&Params) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let params = Params {
+            values: self.values.clone(),
+            read: RefCell::default(),
+        };
+        let value = parse(&params)?;
+        let read = params.read.into_inner();
+        let Some(unknown) = self.values.keys().find(|&key| !read.contains(key)) else {
+            return Ok(value);
+        };
+        let known = if read.is_empty() {
+            "this puzzle takes none".to_owned()
+        } else {
+            let names: Vec<_> = read.iter().map(|key| format!("`{key}`")).collect();
+            format!("expected {}", names.join(", "))
+        };
+        Err(ParseError::new(format!(
+            "unknown parameter `{unknown}` ({known})"
+        )))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> Extend<(K, V)> for Params {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.set(key, value);
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut params = Params::new();
+        params.extend(iter);
+        params
+    }
+}
+
+/// Parses a `key=value` assignment as given to `--param`.
+pub fn parse_assignment(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_owned(), value.trim().to_owned()))
+        }
+        _ => Err(format!("expected `key=value`, got `{s}`")),
+    }
+}
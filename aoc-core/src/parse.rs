@@ -0,0 +1,146 @@
+use std::{error::Error, fmt::Display, ops::Range, str::FromStr};
+
+/// Position of a parse error within the puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The whole line the error occurred on.
+    pub source_line: String,
+}
+
+/// Puzzle input that could not be parsed.
+///
+/// Errors built with [`ParseError::expected`] remember where the offending
+/// text lives in memory, so [`ParseError::locate`] can later resolve it to a
+/// line and column of the full input it was sliced from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub expected: Option<String>,
+    pub found: Option<String>,
+    pub location: Option<Box<Location>>,
+    span: Option<Range<usize>>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            expected: None,
+            found: None,
+            location: None,
+            span: None,
+        }
+    }
+
+    /// An error for `found`, a slice of the input, where `expected` should have been.
+    pub fn expected(expected: impl Into<String>, found: &str) -> Self {
+        let expected = expected.into();
+        let found_desc = if found.is_empty() {
+            "end of line".to_owned()
+        } else {
+            format!("`{found}`")
+        };
+        let start = found.as_ptr() as usize;
+        ParseError {
+            message: format!("expected {expected}, found {found_desc}"),
+            expected: Some(expected),
+            found: Some(found.to_owned()),
+            location: None,
+            span: Some(start..start + found.len()),
+        }
+    }
+
+    /// Resolves the error's location against `input`, the text that was being parsed.
+    ///
+    /// Does nothing if the error is already located or its offending text is
+    /// not a slice of `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let base = input.as_ptr() as usize;
+        let Some(span) = self.span.as_ref() else {
+            return self;
+        };
+        if self.location.is_some() || span.start < base || span.end > base + input.len() {
+            return self;
+        }
+        let offset = span.start - base;
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        self.location = Some(Box::new(Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            source_line: input[line_start..].lines().next().unwrap_or("").to_owned(),
+        }));
+        self
+    }
+
+    /// Renders the error with the offending line and a caret under the bad text.
+    ///
+    /// `origin` names the input, e.g. a file path or `<stdin>`.
+    pub fn diagnostic(&self, origin: &str) -> String {
+        let Some(Location {
+            line,
+            column,
+            source_line,
+        }) = self.location.as_deref()
+        else {
+            return format!("{}\n --> {origin}", self.message);
+        };
+        let gutter = " ".repeat(line.to_string().len());
+        let width = self
+            .found
+            .as_deref()
+            .map_or(1, |found| found.chars().count().max(1));
+        format!(
+            "{message}\n{gutter}--> {origin}:{line}:{column}\n{gutter} |\n{line} | {source_line}\n{gutter} | {pad}{carets}",
+            message = self.message,
+            pad = " ".repeat(column - 1),
+            carets = "^".repeat(width),
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.location.as_deref() {
+            Some(Location { line, column, .. }) => {
+                write!(f, "line {line}, column {column}: {}", self.message)
+            }
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `s` as a number.
+pub fn number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::expected("a number", s))
+}
+
+/// Splits `s` around the first `delim`.
+pub fn split_once<'a>(s: &'a str, delim: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delim)
+        .ok_or_else(|| ParseError::expected(format!("`{delim}`"), &s[s.len()..]))
+}
+
+/// Removes `prefix` from the start of `s`.
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix).ok_or_else(|| {
+        let end = s
+            .char_indices()
+            .nth(prefix.chars().count())
+            .map_or(s.len(), |(i, _)| i);
+        ParseError::expected(format!("`{prefix}`"), &s[..end])
+    })
+}
+
+/// Removes `suffix` from the end of `s`.
+pub fn strip_suffix<'a>(s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+    s.strip_suffix(suffix).ok_or_else(|| {
+        let start = s.char_indices().last().map_or(s.len(), |(i, _)| i);
+        ParseError::expected(format!("`{suffix}`"), &s[start..])
+    })
+}
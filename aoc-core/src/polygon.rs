@@ -0,0 +1,169 @@
+//! Simple polygons on the integer lattice.
+//!
+//! Puzzles that ask how many cells a loop encloses boil down to the shoelace
+//! formula and Pick's theorem, `A = i + b/2 - 1`, relating the area `A` to the
+//! lattice points strictly inside (`i`) and on the boundary (`b`). All counts
+//! are computed in `i128` so long walks cannot overflow.
+
+use crate::geometry::{Dir, Pos};
+
+/// A closed polygon given by its vertices in order; the last one connects
+/// back to the first.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Polygon {
+    vertices: Vec<Pos>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Pos>) -> Self {
+        Polygon { vertices }
+    }
+
+    /// Traces the polygon by walking from `start`, taking `length` steps in
+    /// each direction. The walk is expected to end back at `start`.
+    pub fn from_walk(start: Pos, steps: impl IntoIterator<Item = (Dir, isize)>) -> Self {
+        let vertices = steps
+            .into_iter()
+            .scan(start, |curr, (dir, length)| {
+                let vertex = *curr;
+                *curr += dir * length;
+                Some(vertex)
+            })
+            .collect();
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Pos] {
+        &self.vertices
+    }
+
+    /// Pairs of consecutive vertices, closing the loop.
+    fn edges(&self) -> impl Iterator<Item = (Pos, Pos)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the signed area (shoelace formula), which is always an integer.
+    /// Positive when the vertices run clockwise as drawn with rows growing
+    /// downwards.
+    pub fn double_signed_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.c as i128 * b.r as i128 - a.r as i128 * b.c as i128)
+            .sum()
+    }
+
+    /// The enclosed area, rounded down to a whole number.
+    pub fn area(&self) -> i128 {
+        self.double_signed_area().abs() / 2
+    }
+
+    /// Lattice points on the edges, vertices included.
+    pub fn boundary_points(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| gcd((b.r - a.r).unsigned_abs(), (b.c - a.c).unsigned_abs()) as i128)
+            .sum()
+    }
+
+    /// Lattice points strictly inside the polygon, by Pick's theorem. Only
+    /// meaningful for a simple polygon; one without area has none inside.
+    pub fn interior_points(&self) -> i128 {
+        let double_area = self.double_signed_area().abs();
+        if double_area == 0 {
+            return 0;
+        }
+        (double_area - self.boundary_points() + 2) / 2
+    }
+
+    /// Lattice points inside or on the polygon, i.e. the cells a loop drawn
+    /// through cell centres covers. A walk that only retraces its own steps
+    /// covers the cells along it.
+    pub fn enclosed_points(&self) -> i128 {
+        if self.vertices.is_empty() {
+            return 0;
+        }
+        (self.double_signed_area().abs() + self.boundary_points()) / 2 + 1
+    }
+}
+
+impl FromIterator<Pos> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Pos>>(iter: I) -> Self {
+        Polygon::new(iter.into_iter().collect())
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walk(steps: &[(Dir, isize)]) -> Polygon {
+        Polygon::from_walk(Pos::default(), steps.iter().copied())
+    }
+
+    #[test]
+    fn square() {
+        let square = walk(&[
+            (Dir::Right, 2),
+            (Dir::Down, 2),
+            (Dir::Left, 2),
+            (Dir::Up, 2),
+        ]);
+        assert_eq!(square.double_signed_area(), 8);
+        assert_eq!(square.area(), 4);
+        assert_eq!(square.boundary_points(), 8);
+        assert_eq!(square.interior_points(), 1);
+        assert_eq!(square.enclosed_points(), 9);
+    }
+
+    #[test]
+    fn orientation_only_changes_the_sign() {
+        let cw = walk(&[
+            (Dir::Right, 3),
+            (Dir::Down, 1),
+            (Dir::Left, 3),
+            (Dir::Up, 1),
+        ]);
+        let ccw = walk(&[
+            (Dir::Down, 1),
+            (Dir::Right, 3),
+            (Dir::Up, 1),
+            (Dir::Left, 3),
+        ]);
+        assert_eq!(cw.double_signed_area(), -ccw.double_signed_area());
+        assert_eq!(cw.enclosed_points(), ccw.enclosed_points());
+        assert_eq!(cw.enclosed_points(), 8);
+    }
+
+    #[test]
+    fn degenerate_polygons() {
+        let empty = Polygon::default();
+        assert_eq!(empty.area(), 0);
+        assert_eq!(empty.interior_points(), 0);
+        assert_eq!(empty.enclosed_points(), 0);
+
+        let point = Polygon::new(vec![Pos::new(4, 4)]);
+        assert_eq!(point.interior_points(), 0);
+        assert_eq!(point.enclosed_points(), 1);
+
+        let line = walk(&[(Dir::Right, 3), (Dir::Left, 3)]);
+        assert_eq!(line.area(), 0);
+        assert_eq!(line.interior_points(), 0);
+        assert_eq!(line.enclosed_points(), 4);
+    }
+
+    #[test]
+    fn diagonal_edges_count_lattice_points() {
+        let triangle = Polygon::new(vec![Pos::new(0, 0), Pos::new(0, 4), Pos::new(4, 0)]);
+        assert_eq!(triangle.boundary_points(), 12);
+        assert_eq!(triangle.interior_points(), 3);
+    }
+}
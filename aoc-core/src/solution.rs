@@ -0,0 +1,263 @@
+use std::{
+    any::Any,
+    error::Error,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{checked::Overflow, cli::Part, params::Params, ParseError};
+
+/// A puzzle answer. Days return whatever integer type they compute in.
+/// Every variant serializes as a plain number.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    /// For answers that only fit in 128 bits or are computed in them.
+    Wide(i128),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Wide(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Signed, i64, i32, i64, isize);
+answer_from!(Unsigned, u64, u32, u64, usize);
+answer_from!(Wide, i128, i128);
+
+/// Why a part has no answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartError {
+    /// The answer, or a step towards it, overflowed the type it is computed in.
+    Overflow,
+    /// The input does not have the structure the part relies on, e.g. the
+    /// target cannot be reached.
+    NoAnswer(String),
+}
+
+impl PartError {
+    pub fn no_answer(reason: impl Into<String>) -> Self {
+        PartError::NoAnswer(reason.into())
+    }
+}
+
+impl Display for PartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartError::Overflow => write!(f, "{Overflow}"),
+            PartError::NoAnswer(reason) => write!(f, "no answer: {reason}"),
+        }
+    }
+}
+
+impl Error for PartError {}
+
+impl From<Overflow> for PartError {
+    fn from(Overflow: Overflow) -> Self {
+        PartError::Overflow
+    }
+}
+
+/// A day's puzzle: how to read its input and how to answer both parts.
+pub trait Solution {
+    type Input: 'static;
+    /// Whether there is a second part. The last day has only one, and
+    /// [`Solution::part2`] of a day without one is never run for it.
+    const PART2: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses `input` for a puzzle variant described by `params`. Days with
+    /// tunable constants override this; the rest ignore `params`.
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let _ = params;
+        Self::parse(input)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer, PartError>;
+    fn part2(input: &Self::Input) -> Result<Answer, PartError>;
+
+    /// What the answer to `part` is made of, e.g. the wires cut on the last
+    /// day, for the runner's JSON output.
+    fn notes(input: &Self::Input, part: u8) -> Vec<String> {
+        let _ = (input, part);
+        vec![]
+    }
+}
+
+/// Time spent in each step of a run. Parts that were not run took no time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Answers of the parts that were asked for.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub timings: Timings,
+    /// [`Solution::notes`] on each part that was solved.
+    pub notes: [Vec<String>; 2],
+}
+
+/// Why [`DynSolution::solve`] could not answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// `part` could not be answered.
+    Part {
+        part: u8,
+        error: PartError,
+    },
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(err) => write!(f, "{err}"),
+            SolveError::Part { part, error } => write!(f, "part {part}: {error}"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+/// Parses a day's input into its type-erased [`Solution::Input`].
+pub type ParseFn = fn(&str, &Params) -> Result<Box<dyn Any>, ParseError>;
+
+/// Answers one part of a day's type-erased input.
+pub type PartFn = fn(&dyn Any) -> Result<Answer, PartError>;
+
+/// A day's [`Solution`] with its input type erased, so the runner can keep
+/// every day in one table and still call, and time, each step on its own.
+#[derive(Clone, Copy)]
+pub struct DynSolution {
+    pub parse: ParseFn,
+    pub part1: PartFn,
+    /// `None` for a day with only one part.
+    pub part2: Option<PartFn>,
+    pub notes: fn(&dyn Any, u8) -> Vec<String>,
+}
+
+fn parse_erased<S: Solution>(input: &str, params: &Params) -> Result<Box<dyn Any>, ParseError> {
+    Ok(Box::new(
+        params.consumed_by(|params| S::parse_with(input, params))?,
+    ))
+}
+
+fn part1_erased<S: Solution>(input: &dyn Any) -> Result<Answer, PartError> {
+    S::part1(
+        input
+            .downcast_ref()
+            .expect("input was parsed by another day"),
+    )
+}
+
+fn part2_erased<S: Solution>(input: &dyn Any) -> Result<Answer, PartError> {
+    S::part2(
+        input
+            .downcast_ref()
+            .expect("input was parsed by another day"),
+    )
+}
+
+fn notes_erased<S: Solution>(input: &dyn Any, part: u8) -> Vec<String> {
+    S::notes(
+        input
+            .downcast_ref()
+            .expect("input was parsed by another day"),
+        part,
+    )
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+impl DynSolution {
+    pub const fn of<S: Solution>() -> Self {
+        DynSolution {
+            parse: parse_erased::<S>,
+            part1: part1_erased::<S>,
+            part2: if S::PART2 {
+                Some(part2_erased::<S>)
+            } else {
+                None
+            },
+            notes: notes_erased::<S>,
+        }
+    }
+
+    /// Parses `input` once and solves the selected parts, timing each step.
+    /// Both parts of a day with only one means just that one.
+    pub fn solve(&self, input: &str, params: &Params, part: Part) -> Result<Answers, SolveError> {
+        let (parsed, parse) = timed(|| (self.parse)(input, params));
+        let parsed = parsed.map_err(|err| err.locate(input))?;
+        let mut answers = Answers {
+            timings: Timings {
+                parse,
+                ..Timings::default()
+            },
+            ..Answers::default()
+        };
+        if part != Part::Two {
+            let (answer, elapsed) = timed(|| (self.part1)(&*parsed));
+            answers.part1 = Some(answer.map_err(|error| SolveError::Part { part: 1, error })?);
+            answers.timings.part1 = elapsed;
+            answers.notes[0] = (self.notes)(&*parsed, 1);
+        }
+        match (part, self.part2) {
+            (Part::One, _) | (Part::Both, None) => {}
+            (Part::Two, None) => {
+                return Err(SolveError::Part {
+                    part: 2,
+                    error: PartError::no_answer("there is no second part"),
+                });
+            }
+            (_, Some(part2)) => {
+                let (answer, elapsed) = timed(|| part2(&*parsed));
+                answers.part2 = Some(answer.map_err(|error| SolveError::Part { part: 2, error })?);
+                answers.timings.part2 = elapsed;
+                answers.notes[1] = (self.notes)(&*parsed, 2);
+            }
+        }
+        Ok(answers)
+    }
+}
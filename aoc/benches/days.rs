@@ -0,0 +1,37 @@
+//! Times parsing and both parts of every day on its real input.
+//!
+//! Days without a `day<N>/input` are skipped. Select days with criterion's
+//! filter, e.g. `cargo bench -p aoc -- day12/`.
+
+use std::{fs, hint::black_box, path::Path};
+
+use aoc::days::DAYS;
+use aoc_core::params::Params;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn days(c: &mut Criterion) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for &(day, solution) in DAYS {
+        let Ok(input) = fs::read_to_string(root.join(format!("day{day}/input"))) else {
+            continue;
+        };
+        let params = Params::default();
+        let parsed = (solution.parse)(&input, &params)
+            .unwrap_or_else(|err| panic!("day {day}: {}", err.locate(&input)));
+        let mut group = c.benchmark_group(format!("day{day}"));
+        group.sample_size(10);
+        group.bench_function("parse", |b| {
+            b.iter(|| (solution.parse)(black_box(&input), &params))
+        });
+        group.bench_function("part1", |b| {
+            b.iter(|| (solution.part1)(black_box(&*parsed)))
+        });
+        if let Some(part2) = solution.part2 {
+            group.bench_function("part2", |b| b.iter(|| part2(black_box(&*parsed))));
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+//! Wall-clock summary of how long each day takes on its real input.
+//!
+//! This is a quick ranking for spotting the slow days; use `cargo bench -p aoc`
+//! for statistically sound measurements.
+
+use std::{fs, time::Duration};
+
+use aoc_core::{
+    cli::Part,
+    params::Params,
+    solution::{DynSolution, Timings},
+};
+
+use crate::default_input;
+
+/// Solves `day` `runs` times and keeps the fastest time of each step, or
+/// `None` if the day has no input.
+fn time_day(day: u32, solution: DynSolution, runs: u32) -> Result<Option<Timings>, String> {
+    let Ok(input) = fs::read_to_string(default_input(day)) else {
+        return Ok(None);
+    };
+    let mut best: Option<Timings> = None;
+    for _ in 0..runs.max(1) {
+        let timings = solution
+            .solve(&input, &Params::default(), Part::Both)
+            .map_err(|err| format!("day {day}: {err}"))?
+            .timings;
+        best = Some(match best {
+            None => timings,
+            Some(best) => Timings {
+                parse: best.parse.min(timings.parse),
+                part1: best.part1.min(timings.part1),
+                part2: best.part2.min(timings.part2),
+            },
+        });
+    }
+    Ok(best)
+}
+
+fn ms(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+/// Times every day in `days` and prints them slowest first, limited to `top` rows.
+pub fn summary(days: &[(u32, DynSolution)], runs: u32, top: Option<usize>) -> Result<(), String> {
+    let mut rows = vec![];
+    for &(day, solution) in days {
+        if let Some(timings) = time_day(day, solution, runs)? {
+            rows.push((day, timings));
+        }
+    }
+    rows.sort_by_key(|(_, timings)| std::cmp::Reverse(timings.total()));
+    println!(
+        "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "day", "parse ms", "part 1 ms", "part 2 ms", "total ms"
+    );
+    for (day, timings) in rows.iter().take(top.unwrap_or(rows.len())) {
+        println!(
+            "{day:>3}  {:>12}  {:>12}  {:>12}  {:>12}",
+            ms(timings.parse),
+            ms(timings.part1),
+            ms(timings.part2),
+            ms(timings.total())
+        );
+    }
+    Ok(())
+}
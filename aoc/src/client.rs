@@ -0,0 +1,140 @@
+//! A small client for the Advent of Code website.
+//!
+//! Requests are authenticated with the session cookie from the `AOC_SESSION`
+//! environment variable and spaced out by a rate limit that holds across runs.
+//! The server is configurable so that tests can point it at a local stub.
+
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use clap::Args;
+use ureq::Agent;
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Puzzle year served under the base URL.
+pub const YEAR: u32 = 2023;
+/// When the last request was sent, in milliseconds since the Unix epoch.
+const STAMP_PATH: &str = ".aoc-last-request";
+
+/// Command line options shared by the commands that talk to the server.
+#[derive(Args, Debug, Clone)]
+pub struct ServerArgs {
+    /// Server to talk to.
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+    /// Minimum number of seconds between two requests, also across runs.
+    #[arg(long, default_value_t = 5)]
+    pub min_interval: u64,
+}
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    stamp: PathBuf,
+}
+
+fn now_millis() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+}
+
+impl Client {
+    /// Builds a client for `args`, failing if no session token is set.
+    pub fn from_env(args: &ServerArgs) -> Result<Client, String> {
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or_else(|| {
+                format!("set {SESSION_VAR} to the session cookie of your Advent of Code login")
+            })?;
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(concat!("aoc-2023-runner/", env!("CARGO_PKG_VERSION")))
+            .http_status_as_error(false)
+            .build()
+            .into();
+        Ok(Client {
+            agent,
+            base_url: args.base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+            min_interval: Duration::from_secs(args.min_interval),
+            stamp: PathBuf::from(STAMP_PATH),
+        })
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request, then
+    /// records a new one.
+    fn throttle(&self) {
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|stamp| stamp.trim().parse::<u128>().ok());
+        if let Some(last) = last {
+            let elapsed = Duration::from_millis(now_millis().saturating_sub(last) as u64);
+            if let Some(wait) = self.min_interval.checked_sub(elapsed) {
+                log::info!("rate limit: waiting {:.1}s", wait.as_secs_f64());
+                thread::sleep(wait);
+            }
+        }
+        if let Err(err) = fs::write(&self.stamp, now_millis().to_string()) {
+            log::warn!("cannot record request time in {STAMP_PATH}: {err}");
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    fn check(url: &str, mut response: ureq::http::Response<ureq::Body>) -> Result<String, String> {
+        let status = response.status();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|err| format!("{url}: cannot read response: {err}"))?;
+        if status.is_success() {
+            return Ok(body);
+        }
+        let hint = match status.as_u16() {
+            400 | 401 | 403 => format!(" (is {SESSION_VAR} still valid?)"),
+            404 => " (is the puzzle unlocked yet?)".to_owned(),
+            _ => String::new(),
+        };
+        Err(format!("{url}: server answered {status}{hint}"))
+    }
+
+    /// GETs `path` under the base URL and returns the response body.
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        let url = self.url(path);
+        self.throttle();
+        log::debug!("GET {url}");
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| format!("{url}: {err}"))?;
+        Client::check(&url, response)
+    }
+
+    /// POSTs a form to `path` under the base URL and returns the response body.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let url = self.url(path);
+        self.throttle();
+        log::debug!("POST {url}");
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form(form.iter().copied())
+            .map_err(|err| format!("{url}: {err}"))?;
+        Client::check(&url, response)
+    }
+}
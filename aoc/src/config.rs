@@ -0,0 +1,83 @@
+//! Per-day puzzle parameters from `aoc.toml`.
+//!
+//! The file has one table per day, e.g.
+//!
+//! ```toml
+//! [day21]
+//! steps = 6
+//! ```
+//!
+//! Values override the constants built into each day and are in turn
+//! overridden by `--param key=value` on the command line.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use aoc_core::params::Params;
+use toml::{Table, Value};
+
+pub const PATH: &str = "aoc.toml";
+
+#[derive(Debug, Default)]
+pub struct Config {
+    days: BTreeMap<u32, Params>,
+}
+
+fn param_value(value: Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s),
+        Value::Integer(n) => Some(n.to_string()),
+        Value::Float(x) => Some(x.to_string()),
+        Value::Boolean(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+impl Config {
+    /// Reads the config at `path`; a missing file means no overrides.
+    pub fn load(path: &Path) -> Result<Config, String> {
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+        let table: Table = toml::from_str(&text)
+            .map_err(|err| format!("{}: {}", path.display(), err.message()))?;
+        let mut days = BTreeMap::new();
+        for (name, section) in table {
+            let day = name
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| {
+                    format!(
+                        "{}: expected a `[day<N>]` table, found `{name}`",
+                        path.display()
+                    )
+                })?;
+            let Value::Table(section) = section else {
+                return Err(format!("{}: `{name}` must be a table", path.display()));
+            };
+            let params = section
+                .into_iter()
+                .map(|(key, value)| {
+                    param_value(value)
+                        .map(|value| (key.clone(), value))
+                        .ok_or_else(|| {
+                            format!(
+                                "{}: `{name}.{key}` must be a string, number or boolean",
+                                path.display()
+                            )
+                        })
+                })
+                .collect::<Result<Params, String>>()?;
+            days.insert(day, params);
+        }
+        Ok(Config { days })
+    }
+
+    /// Parameters for `day`: the config file's, then `overrides` on top.
+    pub fn params(&self, day: u32, overrides: &[(String, String)]) -> Params {
+        let mut params = self.days.get(&day).cloned().unwrap_or_default();
+        params.extend(overrides.iter().cloned());
+        params
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_core::{generate::Generator, solution::DynSolution};
+
+/// Every implemented day, in order.
+pub const DAYS: &[(u32, DynSolution)] = &[
+    (1, DynSolution::of::<day1::Day1>()),
+    (2, DynSolution::of::<day2::Day2>()),
+    (3, DynSolution::of::<day3::Day3>()),
+    (4, DynSolution::of::<day4::Day4>()),
+    (5, DynSolution::of::<day5::Day5>()),
+    (6, DynSolution::of::<day6::Day6>()),
+    (7, DynSolution::of::<day7::Day7>()),
+    (8, DynSolution::of::<day8::Day8>()),
+    (9, DynSolution::of::<day9::Day9>()),
+    (10, DynSolution::of::<day10::Day10>()),
+    (11, DynSolution::of::<day11::Day11>()),
+    (12, DynSolution::of::<day12::Day12>()),
+    (13, DynSolution::of::<day13::Day13>()),
+    (14, DynSolution::of::<day14::Day14>()),
+    (15, DynSolution::of::<day15::Day15>()),
+    (16, DynSolution::of::<day16::Day16>()),
+    (17, DynSolution::of::<day17::Day17>()),
+    (18, DynSolution::of::<day18::Day18>()),
+    (19, DynSolution::of::<day19::Day19>()),
+    (20, DynSolution::of::<day20::Day20>()),
+    (21, DynSolution::of::<day21::Day21>()),
+    (22, DynSolution::of::<day22::Day22>()),
+    (23, DynSolution::of::<day23::Day23>()),
+    (24, DynSolution::of::<day24::Day24>()),
+    (25, DynSolution::of::<day25::Day25>()),
+];
+
+pub fn find(day: u32) -> Option<DynSolution> {
+    DAYS.iter()
+        .find(|(number, _)| *number == day)
+        .map(|&(_, solution)| solution)
+}
+
+/// Every day with a generator of random inputs, in order.
+pub const GENERATORS: &[(u32, Generator)] = &[
+    (1, day1::generate::GENERATOR),
+    (2, day2::generate::GENERATOR),
+    (3, day3::generate::GENERATOR),
+    (4, day4::generate::GENERATOR),
+    (5, day5::generate::GENERATOR),
+    (6, day6::generate::GENERATOR),
+    (7, day7::generate::GENERATOR),
+    (8, day8::generate::GENERATOR),
+    (9, day9::generate::GENERATOR),
+    (10, day10::generate::GENERATOR),
+    (11, day11::generate::GENERATOR),
+    (12, day12::generate::GENERATOR),
+    (13, day13::generate::GENERATOR),
+    (14, day14::generate::GENERATOR),
+    (15, day15::generate::GENERATOR),
+    (16, day16::generate::GENERATOR),
+    (17, day17::generate::GENERATOR),
+    (18, day18::generate::GENERATOR),
+    (19, day19::generate::GENERATOR),
+    (20, day20::generate::GENERATOR),
+    (21, day21::generate::GENERATOR),
+    (22, day22::generate::GENERATOR),
+    (23, day23::generate::GENERATOR),
+    (24, day24::generate::GENERATOR),
+    (25, day25::generate::GENERATOR),
+];
+
+pub fn generator(day: u32) -> Option<Generator> {
+    GENERATORS
+        .iter()
+        .find(|(number, _)| *number == day)
+        .map(|&(_, generator)| generator)
+}
@@ -0,0 +1,40 @@
+//! Downloads puzzle inputs to `day<N>/input`.
+
+use std::{fs, path::Path};
+
+use crate::{
+    client::{Client, ServerArgs, YEAR},
+    default_input,
+};
+
+/// Downloads the input of `day` unless it is already cached, so each input
+/// is requested from the server at most once.
+pub fn fetch(day: u32, args: &ServerArgs) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!(
+            "there is no day {day}; puzzles run from day 1 to 25"
+        ));
+    }
+    let path = default_input(day);
+    if path.exists() {
+        println!("{} already exists, not downloading", path.display());
+        return Ok(());
+    }
+    let client = Client::from_env(args)?;
+    let input = client.get(&format!("/{YEAR}/day/{day}/input"))?;
+    save(&path, &input)?;
+    println!("saved {} ({} bytes)", path.display(), input.len());
+    Ok(())
+}
+
+/// Writes `contents` to `path` via a temporary file, so an interrupted
+/// download never leaves a partial input behind to be mistaken for a cached one.
+fn save(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| format!("cannot create {}: {err}", dir.display()))?;
+    }
+    let partial = path.with_extension("partial");
+    fs::write(&partial, contents)
+        .and_then(|()| fs::rename(&partial, path))
+        .map_err(|err| format!("cannot write {}: {err}", path.display()))
+}
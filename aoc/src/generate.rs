@@ -0,0 +1,36 @@
+//! Writes random puzzle inputs with the generators of `aoc_core::generate`.
+
+use std::{fs, path::Path};
+
+use aoc::days;
+
+/// How large an input to generate.
+#[derive(Clone, Copy, Debug)]
+pub enum Size {
+    /// In the day's own unit, such as the side of a grid.
+    Exact(usize),
+    /// As a multiple of the size of the real input.
+    Scale(f64),
+}
+
+/// Generates an input for `day` from `seed` and writes it to `output`, or
+/// prints it if there is none.
+pub fn generate(day: u32, seed: u64, size: Size, output: Option<&Path>) -> Result<(), String> {
+    let generator =
+        days::generator(day).ok_or_else(|| format!("day {day} has no input generator"))?;
+    let size = match size {
+        Size::Exact(size) => size,
+        Size::Scale(scale) if scale > 0.0 => (generator.size as f64 * scale).round() as usize,
+        Size::Scale(scale) => return Err(format!("cannot scale an input by {scale}")),
+    };
+    let input = generator.generate(seed, size);
+    match output {
+        Some(path) => {
+            fs::write(path, &input).map_err(|err| format!("cannot write {}: {err}", path.display()))
+        }
+        None => {
+            print!("{input}");
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,3 @@
+//! Registry of every day's solution, shared by the `aoc` binary and its benchmarks.
+
+pub mod days;
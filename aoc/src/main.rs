@@ -0,0 +1,335 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+    str::FromStr,
+};
+
+use aoc::days;
+use aoc_core::{
+    cli::Part,
+    logging,
+    params::{self, Params},
+    solution::{Answers, DynSolution, SolveError},
+    Answer,
+};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use client::ServerArgs;
+use config::Config;
+use verify::Status;
+
+mod bench;
+mod client;
+mod config;
+mod fetch;
+mod generate;
+mod report;
+mod scaffold;
+mod submit;
+mod verify;
+
+#[derive(Parser, Debug)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log solver diagnostics to stderr; repeat for more detail.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve one day, or every day with `all`.
+    Run {
+        /// Day number, or `all`.
+        day: DaySelection,
+        /// Part of the puzzle to solve.
+        #[arg(long, value_enum, default_value_t = Part::Both)]
+        part: Part,
+        /// Puzzle input. Defaults to `day<N>/input`.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Override a puzzle constant, e.g. `--param steps=6`. Takes
+        /// precedence over `aoc.toml`. Repeatable; not allowed with `all`.
+        #[arg(long = "param", value_name = "KEY=VALUE", value_parser = params::parse_assignment)]
+        params: Vec<(String, String)>,
+        /// How to print the answers.
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Check the answers on the real inputs against `day<N>/answers.toml`
+    /// (or `part1: ...`/`part2: ...` lines in `day<N>/output`). Parameters
+    /// from `aoc.toml` are not applied.
+    Verify {
+        /// Day number, or `all`.
+        #[arg(default_value = "all")]
+        day: DaySelection,
+    },
+    /// Time every day on its real input and list the slowest first.
+    /// Parameters from `aoc.toml` are not applied.
+    Bench {
+        /// Day number, or `all`.
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Runs per day; the fastest time of each step is kept.
+        #[arg(long, default_value_t = 3)]
+        runs: u32,
+        /// Only list this many of the slowest days.
+        #[arg(long)]
+        top: Option<usize>,
+    },
+    /// Download a day's input to `day<N>/input` unless it is already there.
+    /// Needs the session cookie in `AOC_SESSION`.
+    Fetch {
+        day: u32,
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+    /// Solve a part on `day<N>/input` and submit the answer. Every attempt is
+    /// recorded in `day<N>/submissions.jsonl`, and answers known to be wrong
+    /// from earlier attempts are not sent again.
+    Submit {
+        day: u32,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+    /// Create the crate of a new day and register it with the workspace and
+    /// the runner. Run from the repository root.
+    New {
+        day: u32,
+        /// Puzzle title for the crate's doc comment.
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Write a random input for a day, for stress and property testing. The
+    /// same seed and size always give the same input.
+    Generate {
+        day: u32,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size in the day's own unit, such as the side of a grid or the
+        /// number of lines. Defaults to the size of the real input.
+        #[arg(long, conflicts_with = "scale")]
+        size: Option<usize>,
+        /// Size as a multiple of the real input's, e.g. `--scale 10`.
+        #[arg(long)]
+        scale: Option<f64>,
+        /// Where to write the input. Defaults to standard output.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// Output format of `aoc run`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Answers only: one per line for a day, a table for `all`.
+    Text,
+    /// A JSON array with one record per part, including timings in
+    /// nanoseconds and the SHA-256 of the input.
+    Json,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum DaySelection {
+    All,
+    Day(u32),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+        s.parse()
+            .map(DaySelection::Day)
+            .map_err(|_| format!("expected a day number or `all`, got `{s}`"))
+    }
+}
+
+fn default_input(day: u32) -> PathBuf {
+    PathBuf::from(format!("day{day}/input"))
+}
+
+/// Points a parse error at `path`, and names the day of any other.
+fn solve_error(day: u32, path: &Path, err: SolveError) -> String {
+    match err {
+        SolveError::Parse(err) => err.diagnostic(&path.display().to_string()),
+        err => format!("day {day} {err}"),
+    }
+}
+
+fn read_input(path: &PathBuf) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("cannot read {}: {err}", path.display()))
+}
+
+fn run_day(
+    day: u32,
+    part: Part,
+    input: Option<PathBuf>,
+    params: &Params,
+    format: Format,
+) -> Result<(), String> {
+    let solution = days::find(day).ok_or_else(|| format!("day {day} is not implemented"))?;
+    let path = input.unwrap_or_else(|| default_input(day));
+    let input = read_input(&path)?;
+    let answers = solution
+        .solve(&input, params, part)
+        .map_err(|err| solve_error(day, &path, err))?;
+    match format {
+        Format::Text => {
+            for answer in [answers.part1, answers.part2].into_iter().flatten() {
+                println!("{answer}");
+            }
+        }
+        Format::Json => println!(
+            "{}",
+            report::to_json(&report::records(day, &input, answers))
+        ),
+    }
+    Ok(())
+}
+
+fn cell(answer: Option<Answer>) -> String {
+    answer.map(|answer| answer.to_string()).unwrap_or_default()
+}
+
+fn run_all(
+    part: Part,
+    config: &Config,
+    overrides: &[(String, String)],
+    format: Format,
+) -> Result<(), String> {
+    if format == Format::Text {
+        println!("{:>3}  {:>20}  {:>20}", "day", "part 1", "part 2");
+    }
+    let mut records = vec![];
+    let mut failed = 0;
+    for &(day, solution) in days::DAYS {
+        let path = default_input(day);
+        if !path.exists() {
+            if format == Format::Text {
+                println!("{day:>3}  {:>20}  {:>20}", "no input", "no input");
+            }
+            continue;
+        }
+        let solved = read_input(&path).and_then(|input| {
+            solution
+                .solve(&input, &config.params(day, overrides), part)
+                .map(|answers| (input, answers))
+                .map_err(|err| solve_error(day, &path, err))
+        });
+        match (format, solved) {
+            (Format::Text, Ok((_, Answers { part1, part2, .. }))) => {
+                println!("{day:>3}  {:>20}  {:>20}", cell(part1), cell(part2));
+            }
+            (Format::Json, Ok((input, answers))) => {
+                records.extend(report::records(day, &input, answers));
+            }
+            (format, Err(err)) => {
+                if format == Format::Text {
+                    println!("{day:>3}  {:>20}  {:>20}", "failed", "failed");
+                }
+                eprintln!("error: {err}");
+                failed += 1;
+            }
+        }
+    }
+    if format == Format::Json {
+        println!("{}", report::to_json(&records));
+    }
+    if failed > 0 {
+        return Err(format!("{failed} day(s) failed"));
+    }
+    Ok(())
+}
+
+fn selected_days(selection: DaySelection) -> Result<Vec<(u32, DynSolution)>, String> {
+    match selection {
+        DaySelection::All => Ok(days::DAYS.to_vec()),
+        DaySelection::Day(day) => {
+            let solution =
+                days::find(day).ok_or_else(|| format!("day {day} is not implemented"))?;
+            Ok(vec![(day, solution)])
+        }
+    }
+}
+
+fn verify(selection: DaySelection) -> Result<(), String> {
+    let mut failed = 0;
+    for (day, solution) in selected_days(selection)? {
+        for (part, status) in verify::verify_day(day, solution).into_iter().enumerate() {
+            if let Status::Fail(_) = status {
+                failed += 1;
+            }
+            println!("{day:>3}  part {}  {status}", part + 1);
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{failed} answer(s) differ from the recorded ones"));
+    }
+    Ok(())
+}
+
+fn main() {
+    let Cli { command, verbose } = Cli::parse();
+    logging::init(verbose);
+    let result = match command {
+        Command::Run {
+            day: DaySelection::All,
+            input: Some(_),
+            ..
+        } => Err("--input cannot be combined with `all`".to_owned()),
+        Command::Run {
+            day: DaySelection::All,
+            params,
+            ..
+        } if !params.is_empty() => {
+            Err("--param cannot be combined with `all`; set it per day in aoc.toml".to_owned())
+        }
+        Command::Run {
+            day: DaySelection::All,
+            part,
+            input: None,
+            params,
+            format,
+        } => Config::load(Path::new(config::PATH))
+            .and_then(|config| run_all(part, &config, &params, format)),
+        Command::Run {
+            day: DaySelection::Day(day),
+            part,
+            input,
+            params,
+            format,
+        } => Config::load(Path::new(config::PATH))
+            .and_then(|config| run_day(day, part, input, &config.params(day, &params), format)),
+        Command::Verify { day } => verify(day),
+        Command::Bench { day, runs, top } => {
+            selected_days(day).and_then(|days| bench::summary(&days, runs, top))
+        }
+        Command::Fetch { day, server } => fetch::fetch(day, &server),
+        Command::Submit { day, part, server } => submit::submit(day, part, &server),
+        Command::New { day, title } => scaffold::new_day(day, title.as_deref()),
+        Command::Generate {
+            day,
+            seed,
+            size,
+            scale,
+            output,
+        } => {
+            let size = match size {
+                Some(size) => generate::Size::Exact(size),
+                None => generate::Size::Scale(scale.unwrap_or(1.0)),
+            };
+            generate::generate(day, seed, size, output.as_deref())
+        }
+    };
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,59 @@
+//! Machine-readable results of a run, one record per solved part.
+
+use std::time::Duration;
+
+use aoc_core::{solution::Answers, Answer};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u8,
+    pub answer: Answer,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+    /// Identifies which input the answer is for without publishing it.
+    pub input_sha256: String,
+    /// What the answer is made of, for days that say.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+pub fn sha256_hex(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+/// Records for the parts that were solved, in order.
+pub fn records(day: u32, input: &str, answers: Answers) -> Vec<Record> {
+    let input_sha256 = sha256_hex(input);
+    let Answers {
+        part1,
+        part2,
+        timings,
+        notes: [notes1, notes2],
+    } = answers;
+    [
+        (1, part1, timings.part1, notes1),
+        (2, part2, timings.part2, notes2),
+    ]
+    .into_iter()
+    .filter_map(
+        |(part, answer, solve, notes): (u8, Option<Answer>, Duration, Vec<String>)| {
+            Some(Record {
+                day,
+                part,
+                answer: answer?,
+                parse_ns: timings.parse.as_nanos(),
+                solve_ns: solve.as_nanos(),
+                input_sha256: input_sha256.clone(),
+                notes,
+            })
+        },
+    )
+    .collect()
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("records serialize to JSON")
+}
@@ -0,0 +1,125 @@
+//! Creates the crate of a new day from the templates in `aoc/templates` and
+//! registers it with the workspace and the runner.
+
+use std::{fs, path::Path};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs");
+const MAIN_RS: &str = include_str!("../templates/main.rs");
+const EXAMPLES_RS: &str = include_str!("../templates/examples.rs");
+
+/// A file that lists every day on a line of its own, in order.
+struct Registry {
+    path: &'static str,
+    /// The line registering `day`.
+    entry: fn(u32) -> String,
+    /// The day a line registers, if it is an entry.
+    day: fn(&str) -> Option<u32>,
+}
+
+fn leading_number(s: &str) -> Option<u32> {
+    let end = s.find(|ch: char| !ch.is_ascii_digit()).unwrap_or(s.len());
+    s[..end].parse().ok()
+}
+
+const REGISTRIES: &[Registry] = &[
+    Registry {
+        path: "Cargo.toml",
+        entry: |day| format!("    \"day{day}\","),
+        day: |line| leading_number(line.trim().strip_prefix("\"day")?),
+    },
+    Registry {
+        path: "aoc/Cargo.toml",
+        entry: |day| format!("day{day} = {{ path = \"../day{day}\" }}"),
+        day: |line| leading_number(line.strip_prefix("day")?),
+    },
+    Registry {
+        path: "aoc/src/days.rs",
+        entry: |day| format!("    ({day}, DynSolution::of::<day{day}::Day{day}>()),"),
+        day: |line| {
+            let line = line.trim().strip_prefix('(')?;
+            leading_number(line).filter(|_| line.contains("DynSolution"))
+        },
+    },
+];
+
+/// Inserts the entry of `day` among the existing ones, keeping them sorted.
+fn register(registry: &Registry, day: u32) -> Result<(), String> {
+    let path = Path::new(registry.path);
+    let text =
+        fs::read_to_string(path).map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, (registry.day)(line)?)))
+        .collect();
+    if entries.iter().any(|&(_, registered)| registered == day) {
+        return Ok(());
+    }
+    let at = match entries.iter().find(|&&(_, registered)| registered > day) {
+        Some(&(i, _)) => i,
+        None => {
+            entries
+                .last()
+                .ok_or_else(|| format!("cannot find where days are listed in {}", path.display()))?
+                .0
+                + 1
+        }
+    };
+    let entry = (registry.entry)(day);
+    lines.insert(at, &entry);
+    fs::write(path, lines.join("\n") + "\n")
+        .map_err(|err| format!("cannot write {}: {err}", path.display()))
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| format!("cannot create {}: {err}", dir.display()))?;
+    }
+    fs::write(path, contents).map_err(|err| format!("cannot write {}: {err}", path.display()))
+}
+
+/// Creates `day<N>` with a placeholder solution, an empty example and its
+/// test, and adds it to the workspace members, the runner's dependencies
+/// and its list of days.
+pub fn new_day(day: u32, title: Option<&str>) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!(
+            "there is no day {day}; puzzles run from day 1 to 25"
+        ));
+    }
+    let dir = format!("day{day}");
+    let dir = Path::new(&dir);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let header = match title {
+        Some(title) => format!("Day {day}: {title}"),
+        None => format!("Day {day}"),
+    };
+    let fill = |template: &str| {
+        template
+            .replace("__HEADER__", &header)
+            .replace("__DAY__", &day.to_string())
+    };
+    for (file, contents) in [
+        ("Cargo.toml", fill(CARGO_TOML)),
+        ("src/lib.rs", fill(LIB_RS)),
+        ("src/main.rs", fill(MAIN_RS)),
+        ("tests/examples.rs", fill(EXAMPLES_RS)),
+        ("test_input", String::new()),
+        ("test_input.expected", String::new()),
+    ] {
+        write_new(&dir.join(file), &contents)?;
+    }
+    for registry in REGISTRIES {
+        register(registry, day)?;
+    }
+    println!("created {}", dir.display());
+    println!(
+        "paste the example into {0}/test_input and its answers into {0}/test_input.expected",
+        dir.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,294 @@
+//! Submits answers and keeps a history of every attempt.
+//!
+//! Attempts are appended to `day<N>/submissions.jsonl`, one JSON object per
+//! line. The history is consulted before submitting, so that an answer the
+//! server already rejected, or one beyond a known "too high" or "too low"
+//! bound, is never sent again.
+
+use std::{
+    fmt::Display,
+    fs::OpenOptions,
+    io::Write,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::{cli::Part, params::Params, solution::SolveError};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, ServerArgs, YEAR},
+    days, default_input, read_input,
+};
+
+/// What the server made of a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way.
+    Wrong,
+    /// Submitted too soon after the previous attempt; not checked.
+    Wait,
+    /// The part is already solved or not unlocked yet; not checked.
+    AlreadySolved,
+    /// A response this client does not recognise.
+    Unknown,
+}
+
+impl Outcome {
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wrong => "wrong",
+            Outcome::Wait => "rate limited",
+            Outcome::AlreadySolved => "already solved",
+            Outcome::Unknown => "unrecognised response",
+        })
+    }
+}
+
+/// The server's verdict on one submission.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// Seconds to wait before the next submission, when the server says so.
+    pub wait_seconds: Option<u64>,
+    /// The server's message as plain text.
+    pub message: String,
+}
+
+/// Text of the page's `<article>`, or the whole page, with tags removed.
+fn message_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses a duration such as `1m 30s` or `37s`.
+fn parse_wait(text: &str) -> Option<u64> {
+    let mut seconds = 0;
+    for part in text.split_whitespace() {
+        let (n, unit) = part.split_at(part.find(|ch: char| !ch.is_ascii_digit())?);
+        let n: u64 = n.parse().ok()?;
+        seconds += n * match unit {
+            "h" => 3600,
+            "m" => 60,
+            "s" => 1,
+            _ => return None,
+        };
+    }
+    Some(seconds)
+}
+
+pub fn parse_response(html: &str) -> Response {
+    let message = message_text(html);
+    let lower = message.to_lowercase();
+    let mut wait_seconds = None;
+    let outcome = if lower.contains("that's the right answer") {
+        Outcome::Correct
+    } else if lower.contains("not the right answer") {
+        if lower.contains("too high") {
+            Outcome::TooHigh
+        } else if lower.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if lower.contains("answer too recently") {
+        wait_seconds = lower
+            .split_once(" left to wait")
+            .and_then(|(before, _)| before.rsplit_once("you have "))
+            .and_then(|(_, wait)| parse_wait(wait));
+        Outcome::Wait
+    } else if lower.contains("solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown
+    };
+    Response {
+        outcome,
+        wait_seconds,
+        message,
+    }
+}
+
+/// One line of the submission history.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Attempt {
+    /// Seconds since the Unix epoch.
+    time: u64,
+    part: u8,
+    answer: String,
+    outcome: Outcome,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_seconds: Option<u64>,
+}
+
+fn history_path(day: u32) -> PathBuf {
+    PathBuf::from(format!("day{day}/submissions.jsonl"))
+}
+
+fn read_history(day: u32) -> Result<Vec<Attempt>, String> {
+    let path = history_path(day);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let text = read_input(&path)?;
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|err| format!("{}:{}: {err}", path.display(), i + 1))
+        })
+        .collect()
+}
+
+fn record(day: u32, attempt: &Attempt) -> Result<(), String> {
+    let path = history_path(day);
+    let line = serde_json::to_string(attempt).expect("attempts serialize to JSON");
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{line}"))
+        .map_err(|err| format!("cannot record the attempt in {}: {err}", path.display()))
+}
+
+/// Why `answer` need not be submitted, judging by earlier attempts: `Ok`
+/// when it was already accepted, `Err` when it is known to be wrong.
+fn known_verdict(history: &[Attempt], part: u8, answer: &str) -> Option<Result<String, String>> {
+    let attempts = || history.iter().filter(move |attempt| attempt.part == part);
+    if let Some(correct) = attempts().find(|attempt| attempt.outcome == Outcome::Correct) {
+        return Some(if correct.answer == answer {
+            Ok(format!("{answer} was already accepted"))
+        } else {
+            Err(format!(
+                "this part was already solved with {}, not {answer}",
+                correct.answer
+            ))
+        });
+    }
+    if let Some(rejected) =
+        attempts().find(|attempt| attempt.answer == answer && attempt.outcome.is_wrong())
+    {
+        return Some(Err(format!(
+            "{answer} was already rejected as {}",
+            rejected.outcome
+        )));
+    }
+    let value: i128 = answer.parse().ok()?;
+    attempts().find_map(|attempt| {
+        let bound: i128 = attempt.answer.parse().ok()?;
+        match attempt.outcome {
+            Outcome::TooHigh if value >= bound => Some(Err(format!(
+                "{answer} cannot be right: {bound} was already too high"
+            ))),
+            Outcome::TooLow if value <= bound => Some(Err(format!(
+                "{answer} cannot be right: {bound} was already too low"
+            ))),
+            _ => None,
+        }
+    })
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Solves `part` of `day` on its real input and submits the answer, unless
+/// the history already decides it.
+pub fn submit(day: u32, part: u8, args: &ServerArgs) -> Result<(), String> {
+    let solution = days::find(day).ok_or_else(|| format!("day {day} is not implemented"))?;
+    let path = default_input(day);
+    let input = read_input(&path)?;
+    let which = if part == 1 { Part::One } else { Part::Two };
+    let answers = solution
+        .solve(&input, &Params::default(), which)
+        .map_err(|err| match err {
+            SolveError::Parse(err) => err.diagnostic(&path.display().to_string()),
+            err => format!("day {day} {err}"),
+        })?;
+    let answer = [answers.part1, answers.part2][usize::from(part - 1)]
+        .as_ref()
+        .expect("the requested part was solved")
+        .to_string();
+
+    match known_verdict(&read_history(day)?, part, &answer) {
+        Some(Ok(message)) => {
+            println!("day {day} part {part}: {message}, not submitting");
+            return Ok(());
+        }
+        Some(Err(message)) => {
+            return Err(format!("day {day} part {part}: {message}, not submitting"))
+        }
+        None => {}
+    }
+
+    let client = Client::from_env(args)?;
+    let html = client.post_form(
+        &format!("/{YEAR}/day/{day}/answer"),
+        &[("level", &part.to_string()), ("answer", &answer)],
+    )?;
+    let response = parse_response(&html);
+    log::debug!("server says: {}", response.message);
+    record(
+        day,
+        &Attempt {
+            time: now_secs(),
+            part,
+            answer: answer.clone(),
+            outcome: response.outcome,
+            wait_seconds: response.wait_seconds,
+        },
+    )?;
+    let prefix = format!("day {day} part {part}");
+    match response.outcome {
+        Outcome::Correct => {
+            println!("{prefix}: {answer} is correct");
+            Ok(())
+        }
+        Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong => {
+            Err(format!("{prefix}: {answer} is {}", response.outcome))
+        }
+        Outcome::Wait => Err(match response.wait_seconds {
+            Some(seconds) => format!(
+                "{prefix}: {answer} was not checked; wait {seconds}s before submitting again"
+            ),
+            None => format!("{prefix}: {answer} was not checked; wait before submitting again"),
+        }),
+        Outcome::AlreadySolved => Err(format!(
+            "{prefix}: {answer} was not checked; the part is already solved or not unlocked yet"
+        )),
+        Outcome::Unknown => Err(format!(
+            "{prefix}: unrecognised response: {}",
+            response.message
+        )),
+    }
+}
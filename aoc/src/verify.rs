@@ -0,0 +1,153 @@
+//! Checks each day's answers on its real input against recorded golden values.
+
+use std::{fmt::Display, fs, path::PathBuf};
+
+use aoc_core::{
+    cli::Part,
+    params::Params,
+    solution::{Answers, DynSolution},
+};
+use serde::Deserialize;
+
+use crate::default_input;
+
+/// One recorded answer, written in `answers.toml` as a number or a string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Recorded {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Recorded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Recorded::Number(n) => write!(f, "{n}"),
+            Recorded::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Contents of `day<N>/answers.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnswersFile {
+    part1: Option<Recorded>,
+    part2: Option<Recorded>,
+}
+
+/// Outcome of checking one part.
+pub enum Status {
+    Pass,
+    Fail(String),
+    Missing(&'static str),
+    /// The day has no such part.
+    NotApplicable,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail(reason) => write!(f, "FAIL     {reason}"),
+            Status::Missing(reason) => write!(f, "MISSING  {reason}"),
+            Status::NotApplicable => write!(f, "n/a"),
+        }
+    }
+}
+
+fn day_file(day: u32, name: &str) -> PathBuf {
+    PathBuf::from(format!("day{day}/{name}"))
+}
+
+/// Reads the golden answers of `day`, preferring `answers.toml` over
+/// `part1: <answer>` and `part2: <answer>` lines in a recorded `output`.
+/// `None` if neither holds a snapshot.
+fn recorded_answers(day: u32) -> Result<Option<[Option<String>; 2]>, String> {
+    let toml_path = day_file(day, "answers.toml");
+    if toml_path.exists() {
+        let text = fs::read_to_string(&toml_path)
+            .map_err(|err| format!("cannot read {}: {err}", toml_path.display()))?;
+        let AnswersFile { part1, part2 } = toml::from_str(&text)
+            .map_err(|err| format!("{}: {}", toml_path.display(), err.message()))?;
+        return Ok(Some(
+            [part1, part2].map(|answer| answer.map(|answer| answer.to_string())),
+        ));
+    }
+    let output_path = day_file(day, "output");
+    if output_path.exists() {
+        let text = fs::read_to_string(&output_path)
+            .map_err(|err| format!("cannot read {}: {err}", output_path.display()))?;
+        let answer = |label: &str| {
+            text.lines()
+                .rev()
+                .find_map(|line| line.strip_prefix(label))
+                .map(|answer| answer.trim().to_owned())
+        };
+        let answers = [answer("part1:"), answer("part2:")];
+        if answers != [None, None] {
+            return Ok(Some(answers));
+        }
+    }
+    Ok(None)
+}
+
+/// Solves `day` on its input and compares both parts with the recorded
+/// answers. Part 2 is n/a on a day without one.
+pub fn verify_day(day: u32, solution: DynSolution) -> [Status; 2] {
+    let mut statuses = verify_parts(day, solution);
+    if solution.part2.is_none() {
+        statuses[1] = Status::NotApplicable;
+    }
+    statuses
+}
+
+fn verify_parts(day: u32, solution: DynSolution) -> [Status; 2] {
+    let input_path = default_input(day);
+    let Ok(input) = fs::read_to_string(&input_path) else {
+        return [Status::Missing("no input"), Status::Missing("no input")];
+    };
+    let mut expected = match recorded_answers(day) {
+        Ok(Some(expected)) => expected,
+        Ok(None) => {
+            return [
+                Status::Missing("no snapshot"),
+                Status::Missing("no snapshot"),
+            ]
+        }
+        Err(err) => return [Status::Fail(err.clone()), Status::Fail(err)],
+    };
+    if solution.part2.is_none() {
+        expected[1] = None;
+    }
+    let part = match &expected {
+        [Some(_), Some(_)] => Part::Both,
+        [Some(_), None] => Part::One,
+        [None, Some(_)] => Part::Two,
+        [None, None] => {
+            return [
+                Status::Missing("no recorded answer"),
+                Status::Missing("no recorded answer"),
+            ]
+        }
+    };
+    let Answers { part1, part2, .. } = match solution.solve(&input, &Params::default(), part) {
+        Ok(answers) => answers,
+        Err(err) => {
+            let err = err.to_string();
+            return [Status::Fail(err.clone()), Status::Fail(err)];
+        }
+    };
+    let [expected1, expected2] = expected;
+    [(expected1, part1), (expected2, part2)].map(|(expected, actual)| match expected {
+        None => Status::Missing("no recorded answer"),
+        Some(expected) => {
+            let actual = actual.map(|answer| answer.to_string()).unwrap_or_default();
+            if actual == expected {
+                Status::Pass
+            } else {
+                Status::Fail(format!("expected {expected}, got {actual}"))
+            }
+        }
+    })
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_core::examples::check::<day__DAY__::Day__DAY__>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,36 @@
+//! __HEADER__
+
+use std::str::FromStr;
+
+use aoc_core::{Answer, ParseError, PartError, Solution};
+
+pub struct Input {
+    pub lines: Vec<String>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().map(str::to_owned).collect();
+        Ok(Self { lines })
+    }
+}
+
+pub struct Day__DAY__;
+
+impl Solution for Day__DAY__ {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(_input: &Input) -> Result<Answer, PartError> {
+        Ok(0.into())
+    }
+
+    fn part2(_input: &Input) -> Result<Answer, PartError> {
+        Ok(0.into())
+    }
+}
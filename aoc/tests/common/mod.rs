@@ -0,0 +1,118 @@
+//! A local stand-in for the Advent of Code server, and a scratch workspace
+//! to run the `aoc` binary in.
+
+#![allow(dead_code)]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use tiny_http::{Header, Response, Server};
+
+/// One request the stub received.
+#[derive(Clone, Debug)]
+pub struct Received {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+pub struct Stub {
+    pub base_url: String,
+    received: Arc<Mutex<Vec<Received>>>,
+}
+
+impl Stub {
+    /// Serves `respond(request)` as (status, body) for every request.
+    pub fn start(respond: impl Fn(&Received) -> (u16, String) + Send + 'static) -> Stub {
+        let server = Server::http("127.0.0.1:0").expect("stub server starts");
+        let base_url = format!("http://{}", server.server_addr());
+        let received = Arc::new(Mutex::new(vec![]));
+        let log = Arc::clone(&received);
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).ok();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string());
+                let received = Received {
+                    method: request.method().to_string(),
+                    url: request.url().to_owned(),
+                    cookie,
+                    body,
+                };
+                let (status, body) = respond(&received);
+                log.lock().unwrap().push(received);
+                let content_type =
+                    Header::from_bytes("Content-Type", "text/html; charset=utf-8").unwrap();
+                let response = Response::from_string(body)
+                    .with_status_code(status)
+                    .with_header(content_type);
+                request.respond(response).ok();
+            }
+        });
+        Stub { base_url, received }
+    }
+
+    pub fn received(&self) -> Vec<Received> {
+        self.received.lock().unwrap().clone()
+    }
+}
+
+/// An empty directory to run `aoc` in, removed again when dropped.
+pub struct Workspace(PathBuf);
+
+impl Workspace {
+    pub fn new(name: &str) -> Workspace {
+        let dir = std::env::temp_dir().join(format!("aoc-test-{}-{name}", std::process::id()));
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir_all(&dir).unwrap();
+        Workspace(dir)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    pub fn join(&self, path: &str) -> PathBuf {
+        self.0.join(path)
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.0).ok();
+    }
+}
+
+/// Runs the `aoc` binary in `dir` with `session` as the session token.
+pub fn aoc(dir: &Path, session: Option<&str>, args: &[&str]) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command
+        .args(args)
+        .current_dir(dir)
+        .env_remove("AOC_SESSION")
+        .env_remove("AOC_BASE_URL");
+    if let Some(session) = session {
+        command.env("AOC_SESSION", session);
+    }
+    command.output().expect("aoc runs")
+}
+
+pub fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+pub fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use std::fs;
+
+use common::{aoc, stderr, Stub, Workspace};
+
+const INPUT: &str = "467..114..\n...*......\n";
+
+fn fetch(
+    stub: &Stub,
+    dir: &std::path::Path,
+    session: Option<&str>,
+    day: &str,
+) -> std::process::Output {
+    aoc(
+        dir,
+        session,
+        &[
+            "fetch",
+            day,
+            "--base-url",
+            &stub.base_url,
+            "--min-interval",
+            "0",
+        ],
+    )
+}
+
+#[test]
+fn downloads_input_with_session_cookie() {
+    let stub = Stub::start(|_| (200, INPUT.to_owned()));
+    let dir = Workspace::new("fetch-downloads");
+
+    let output = fetch(&stub, dir.path(), Some("secret"), "3");
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(fs::read_to_string(dir.join("day3/input")).unwrap(), INPUT);
+    let received = stub.received();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].method, "GET");
+    assert_eq!(received[0].url, "/2023/day/3/input");
+    assert_eq!(received[0].cookie.as_deref(), Some("session=secret"));
+}
+
+#[test]
+fn never_downloads_a_cached_input() {
+    let stub = Stub::start(|_| (200, INPUT.to_owned()));
+    let dir = Workspace::new("fetch-cached");
+    fs::create_dir_all(dir.join("day5")).unwrap();
+    fs::write(dir.join("day5/input"), "mine").unwrap();
+
+    let output = fetch(&stub, dir.path(), None, "5");
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(fs::read_to_string(dir.join("day5/input")).unwrap(), "mine");
+    assert!(stub.received().is_empty());
+}
+
+#[test]
+fn requires_a_session_token() {
+    let stub = Stub::start(|_| (200, INPUT.to_owned()));
+    let dir = Workspace::new("fetch-no-session");
+
+    let output = fetch(&stub, dir.path(), None, "3");
+
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("AOC_SESSION"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(stub.received().is_empty());
+}
+
+#[test]
+fn reports_server_errors_without_caching() {
+    let stub = Stub::start(|_| (404, "Not Found".to_owned()));
+    let dir = Workspace::new("fetch-404");
+
+    let output = fetch(&stub, dir.path(), Some("secret"), "25");
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("404"), "{}", stderr(&output));
+    assert!(!dir.join("day25/input").exists());
+}
+
+#[test]
+fn waits_between_requests() {
+    let stub = Stub::start(|_| (200, INPUT.to_owned()));
+    let dir = Workspace::new("fetch-rate-limit");
+    let fetch = |day: &str| {
+        aoc(
+            dir.path(),
+            Some("secret"),
+            &[
+                "fetch",
+                day,
+                "--base-url",
+                &stub.base_url,
+                "--min-interval",
+                "1",
+            ],
+        )
+    };
+
+    let start = std::time::Instant::now();
+    assert!(fetch("1").status.success());
+    assert!(fetch("2").status.success());
+
+    assert!(start.elapsed() >= std::time::Duration::from_secs(1));
+    assert_eq!(stub.received().len(), 2);
+}
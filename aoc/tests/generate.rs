@@ -0,0 +1,123 @@
+mod common;
+
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+};
+
+use aoc::days;
+use aoc_core::{cli::Part, params::Params};
+use common::{aoc, stderr, stdout, Workspace};
+
+#[test]
+fn same_seed_same_input() {
+    let dir = Workspace::new("generate");
+    let generate = |seed: &str| {
+        let output = aoc(
+            dir.path(),
+            None,
+            &["generate", "10", "--seed", seed, "--size", "20"],
+        );
+        assert!(output.status.success(), "{}", stderr(&output));
+        stdout(&output)
+    };
+
+    assert_eq!(generate("7"), generate("7"));
+    assert_ne!(generate("7"), generate("8"));
+    assert_eq!(generate("7").lines().count(), 20);
+}
+
+#[test]
+fn writes_the_input_to_a_file() {
+    let dir = Workspace::new("generate-output");
+
+    let output = aoc(
+        dir.path(),
+        None,
+        &["generate", "11", "--scale", "0.1", "--output", "input"],
+    );
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let input = fs::read_to_string(dir.join("input")).unwrap();
+    assert_eq!(input.lines().count(), 14);
+    assert!(input.lines().all(|line| line.len() == 14));
+}
+
+#[test]
+fn refuses_days_without_a_generator() {
+    let dir = Workspace::new("generate-unknown");
+
+    let output = aoc(dir.path(), None, &["generate", "26"]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("day 26 has no input generator"));
+}
+
+/// Parameters for a generated input of `size`, where a day's defaults only
+/// fit the real input.
+fn params(day: u32, size: usize) -> Params {
+    match day {
+        21 => {
+            let side = size | 1;
+            Params::from_iter([("far_steps", (side / 2 + 5 * side).to_string())])
+        }
+        _ => Params::new(),
+    }
+}
+
+/// Asserts that the input of `day` generated from `seed` at `size` parses
+/// and has an answer to every part, the one planted in it if any.
+fn assert_solves(day: u32, seed: u64, size: usize) {
+    let generator = days::generator(day).unwrap();
+    let solution = days::find(day).unwrap();
+    let input = generator.generate(seed, size);
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        solution.solve(&input, &params(day, size), Part::Both)
+    }));
+    match solved {
+        Ok(Ok(answers)) => {
+            assert!(answers.part1.is_some());
+            assert_eq!(answers.part2.is_some(), solution.part2.is_some());
+            let solved = [answers.part1, answers.part2];
+            for (part, (planted, solved)) in generator
+                .answers(seed, size)
+                .into_iter()
+                .zip(solved)
+                .enumerate()
+            {
+                if let Some(planted) = planted {
+                    assert_eq!(
+                        solved,
+                        Some(planted),
+                        "day {day} part {}, seed {seed}, size {size}",
+                        part + 1
+                    );
+                }
+            }
+        }
+        Ok(Err(err)) => panic!("day {day}, seed {seed}, size {size}: {err}\n{input}"),
+        Err(_) => panic!("day {day} panicked on the input of seed {seed}, size {size}"),
+    }
+}
+
+/// Every generator writes inputs that parse and solve, here at a tenth of
+/// the real size so that it stays quick in debug builds.
+#[test]
+fn generated_inputs_solve() {
+    for &(day, generator) in days::GENERATORS {
+        for seed in 0..5 {
+            assert_solves(day, seed, (generator.size / 10).max(3));
+        }
+    }
+}
+
+/// Sizes too small for a puzzle are raised to the smallest that works,
+/// e.g. five blocks on day 17 and three hailstones on day 24.
+#[test]
+fn smallest_generated_inputs_solve() {
+    for &(day, _) in days::GENERATORS {
+        for size in 1..=3 {
+            assert_solves(day, 0, size);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use common::{aoc, stderr, Workspace};
+
+/// The files that list the days, with the line that registers `day`.
+fn registries(day: u32) -> [(&'static str, String); 3] {
+    [
+        ("Cargo.toml", format!("    \"day{day}\",")),
+        (
+            "aoc/Cargo.toml",
+            format!("day{day} = {{ path = \"../day{day}\" }}"),
+        ),
+        (
+            "aoc/src/days.rs",
+            format!("    ({day}, DynSolution::of::<day{day}::Day{day}>()),"),
+        ),
+    ]
+}
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// A workspace with copies of the files that list the days, with `day`
+/// taken out of them so that `aoc new` has something to insert.
+fn workspace(name: &str, day: u32) -> Workspace {
+    let dir = Workspace::new(name);
+    fs::create_dir_all(dir.join("aoc/src")).unwrap();
+    for (file, entry) in registries(day) {
+        let text = fs::read_to_string(root().join(file)).unwrap();
+        let stripped: String = text
+            .lines()
+            .filter(|&line| line != entry)
+            .map(|line| format!("{line}\n"))
+            .collect();
+        assert_ne!(stripped, text, "{entry} missing from {file}");
+        fs::write(dir.join(file), stripped).unwrap();
+    }
+    dir
+}
+
+fn read(dir: &Workspace, file: &str) -> String {
+    fs::read_to_string(dir.join(file)).unwrap()
+}
+
+/// Asserts that registering `day` put back exactly the lines that
+/// [`workspace`] took out, where they were.
+fn assert_registered(dir: &Workspace, day: u32) {
+    for (file, entry) in registries(day) {
+        let text = read(dir, file);
+        assert_eq!(
+            text.lines().filter(|&line| line == entry).count(),
+            1,
+            "{entry} in {file}:\n{text}"
+        );
+        assert_eq!(
+            text,
+            fs::read_to_string(root().join(file)).unwrap(),
+            "{file}"
+        );
+    }
+}
+
+#[test]
+fn creates_and_registers_a_day() {
+    let dir = workspace("new-day", 17);
+
+    let output = aoc(
+        dir.path(),
+        None,
+        &["new", "17", "--title", "Clumsy Crucible"],
+    );
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let lib = read(&dir, "day17/src/lib.rs");
+    assert!(lib.starts_with("//! Day 17: Clumsy Crucible\n"), "{lib}");
+    assert!(lib.contains("impl Solution for Day17"), "{lib}");
+    assert!(read(&dir, "day17/Cargo.toml").contains("name = \"day17\""));
+    assert!(read(&dir, "day17/src/main.rs").contains("day17::Day17"));
+    assert!(read(&dir, "day17/tests/examples.rs").contains("day17::Day17"));
+    assert_eq!(read(&dir, "day17/test_input"), "");
+    assert_eq!(read(&dir, "day17/test_input.expected"), "");
+    assert_registered(&dir, 17);
+}
+
+#[test]
+fn appends_a_day_after_the_last_one() {
+    let dir = workspace("new-last-day", 25);
+
+    let output = aoc(dir.path(), None, &["new", "25"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(read(&dir, "day25/src/lib.rs").starts_with("//! Day 25\n"));
+    assert_registered(&dir, 25);
+}
+
+/// The scaffolded crate builds, tests included, against the real
+/// `aoc-core` in a workspace of its own.
+#[test]
+fn scaffolded_day_builds() {
+    let dir = workspace("new-day-builds", 17);
+    let output = aoc(dir.path(), None, &["new", "17"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    let manifest = read(&dir, "Cargo.toml");
+    let (head, rest) = manifest.split_once("members = [").unwrap();
+    let (_, rest) = rest.split_once(']').unwrap();
+    let aoc_core = root().join("aoc-core").canonicalize().unwrap();
+    let manifest = format!("{head}members = [\"day17\"]{rest}").replace(
+        "aoc-core = { path = \"aoc-core\" }",
+        &format!(
+            "aoc-core = {{ path = {:?} }}",
+            aoc_core.display().to_string()
+        ),
+    );
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    fs::copy(root().join("Cargo.lock"), dir.join("Cargo.lock")).unwrap();
+
+    let output = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned()))
+        .args(["check", "--offline", "--all-targets", "--quiet"])
+        .current_dir(dir.path())
+        .env("CARGO_TARGET_DIR", dir.join("target"))
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{}", stderr(&output));
+}
+
+#[test]
+fn refuses_existing_and_impossible_days() {
+    let dir = workspace("new-existing-day", 17);
+    fs::create_dir(dir.join("day17")).unwrap();
+    let cargo_toml = read(&dir, "Cargo.toml");
+
+    let output = aoc(dir.path(), None, &["new", "17"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("day17 already exists"),
+        "{}",
+        stderr(&output)
+    );
+
+    let output = aoc(dir.path(), None, &["new", "26"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("no day 26"), "{}", stderr(&output));
+    assert_eq!(read(&dir, "Cargo.toml"), cargo_toml);
+}
@@ -0,0 +1,301 @@
+mod common;
+
+use std::fs;
+
+use common::{aoc, stderr, stdout, Workspace};
+
+#[test]
+fn reports_which_part_overflowed() {
+    let dir = Workspace::new("run-overflow");
+    fs::write(dir.join("cards"), day4::generate::cascade(100)).unwrap();
+
+    let output = aoc(dir.path(), None, &["run", "4", "--input", "cards"]);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("day 4 part 2: arithmetic overflow"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn run_all_reports_failed_days_and_fails() {
+    let dir = Workspace::new("run-all-failed");
+    for (day, input) in [(1, "1abc2\n".to_owned()), (4, day4::generate::cascade(100))] {
+        fs::create_dir_all(dir.join(&format!("day{day}"))).unwrap();
+        fs::write(dir.join(&format!("day{day}/input")), input).unwrap();
+    }
+
+    let output = aoc(dir.path(), None, &["run", "all"]);
+
+    assert!(!output.status.success());
+    let stdout = stdout(&output);
+    assert!(
+        stdout.contains("  1                    12                    12\n"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("  4                failed                failed\n"),
+        "{stdout}"
+    );
+    let stderr = stderr(&output);
+    assert!(
+        stderr.contains("error: day 4 part 2: arithmetic overflow"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("error: 1 day(s) failed"), "{stderr}");
+    assert!(!stdout.contains("overflow"), "{stdout}");
+}
+
+#[test]
+fn reports_parts_without_an_answer() {
+    let dir = Workspace::new("run-no-answer");
+    fs::write(dir.join("blocks"), "123\n456\n789\n").unwrap();
+
+    let output = aoc(dir.path(), None, &["run", "17", "--input", "blocks"]);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output)
+            .contains("day 17 part 2: no answer: the crucible cannot stop at the factory"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn points_at_malformed_input() {
+    let dir = Workspace::new("run-malformed");
+    for (day, input, location, message) in [
+        (
+            1,
+            "1abc\neightwothree\nabc\n",
+            "bad:3:1",
+            "expected a line with a digit, in figures or spelled out, found `abc`",
+        ),
+        (
+            4,
+            "Card 1: 1 x | 2\n",
+            "bad:1:11",
+            "expected a number, found `x`",
+        ),
+        (
+            4,
+            "Card 1: 1 | 1\nCard 3: 2 | 2\n",
+            "bad:2:6",
+            "expected card 2, found `3`",
+        ),
+        (
+            6,
+            "Time: 7 15\nDistance: 9\n",
+            "bad:2:1",
+            "expected 2 distances",
+        ),
+        (7, "32T3K 765\nT55X5 684\n", "bad:2:1", "five cards"),
+        (
+            8,
+            "L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n",
+            "bad:3:8",
+            "expected a node of the map, found `BBB`",
+        ),
+        (
+            8,
+            "\n\nAAA = (AAA, AAA)\n",
+            "bad:1:1",
+            "expected `L` or `R`, found end of line",
+        ),
+        (
+            9,
+            "1 2 3\n4 x 6\n",
+            "bad:2:3",
+            "expected a number, found `x`",
+        ),
+        (11, "..#\n.#\n", "bad:2:1", "expected a row of 3 cells"),
+        (
+            12,
+            "??a 1\n",
+            "bad:1:3",
+            "expected `.`, `#` or `?`, found `a`",
+        ),
+        (
+            12,
+            "???.### 1,x,3\n",
+            "bad:1:11",
+            "expected a number, found `x`",
+        ),
+        (12, "?? 0\n", "bad:1:4", "a group of at least one spring"),
+        (
+            14,
+            "O.\n.x\n",
+            "bad:2:2",
+            "expected one of `.`, `#`, `O`, found `x`",
+        ),
+        (16, ".|\nx.\n", "bad:2:1", "one of `.`, `-`, `|`, `/`, `\\`"),
+        (17, "12a\n", "bad:1:3", "expected a digit, found `a`"),
+        (18, "R 6 (#70c71)\n", "bad:1:7", "six hexadecimal digits"),
+        (18, "X 6 (#70c710)\n", "bad:1:1", "`R`, `L`, `U` or `D`"),
+        (
+            19,
+            "in{x<5:A,foo}\n\n{x=1,m=2,a=3,s=4}\n",
+            "bad:1:10",
+            "expected a workflow, `A` or `R`, found `foo`",
+        ),
+        (
+            19,
+            "in{x<5:A,x>7:R}\n",
+            "bad:1:10",
+            "expected a fallback workflow, found `x>7:R`",
+        ),
+        (19, "px{A}\n", "bad", "expected a workflow named `in`"),
+        (
+            22,
+            "1,1,8~1,1,7\n",
+            "bad:1:7",
+            "expected an end no lower than `1,1,8` on any axis",
+        ),
+        (22, "", "bad:1:1", "expected a brick"),
+        (23, "#.#\n#x#\n", "bad:2:2", "one of `#`, `.`"),
+    ] {
+        fs::write(dir.join("bad"), input).unwrap();
+
+        let output = aoc(
+            dir.path(),
+            None,
+            &["run", &day.to_string(), "--input", "bad"],
+        );
+
+        let stderr = stderr(&output);
+        assert!(
+            !output.status.success(),
+            "day {day}: {input:?} was accepted"
+        );
+        assert!(
+            stderr.contains(location) && stderr.contains(message),
+            "day {day}:\n{stderr}"
+        );
+    }
+}
+
+#[test]
+fn rejects_unknown_and_invalid_parameters() {
+    let dir = Workspace::new("run-params");
+    fs::write(dir.join("galaxies"), "#.\n.#\n").unwrap();
+    fs::write(dir.join("garden"), "...\n.S.\n...\n").unwrap();
+    fs::write(dir.join("springs"), "???.### 1,1,3\n").unwrap();
+    fs::write(dir.join("blocks"), "123\n456\n").unwrap();
+    for (config, args, message) in [
+        (
+            "",
+            &["run", "11", "--input", "galaxies", "--param", "expanson=10"][..],
+            "unknown parameter `expanson` (expected `expansion`)",
+        ),
+        (
+            "[day11]\nexpanson = 10\n",
+            &["run", "11", "--input", "galaxies"],
+            "unknown parameter `expanson`",
+        ),
+        (
+            "",
+            &["run", "13", "--input", "galaxies", "--param", "steps=6"],
+            "unknown parameter `steps` (this puzzle takes none)",
+        ),
+        (
+            "",
+            &["run", "all", "--param", "steps=6"],
+            "--param cannot be combined with `all`",
+        ),
+        (
+            "",
+            &["run", "11", "--input", "galaxies", "--param", "expansion=0"],
+            "`expansion` must be at least 1",
+        ),
+        (
+            "",
+            &["run", "12", "--input", "springs", "--param", "folds=0"],
+            "`folds` must be at least 1",
+        ),
+        (
+            "",
+            &["run", "17", "--input", "blocks", "--param", "max_run=0"],
+            "`max_run` must be at least 1",
+        ),
+        (
+            "",
+            &[
+                "run",
+                "17",
+                "--input",
+                "blocks",
+                "--param",
+                "ultra_min_run=11",
+            ],
+            "`ultra_min_run` must be at most `ultra_max_run`, 10, got 11",
+        ),
+        (
+            "",
+            &["run", "21", "--input", "garden", "--param", "far_steps=2"],
+            "day 21 part 2: no answer: `far_steps` must be 1 + 3k for a map 3 wide, got 2",
+        ),
+    ] {
+        fs::write(dir.join("aoc.toml"), config).unwrap();
+
+        let output = aoc(dir.path(), None, args);
+
+        assert!(!output.status.success(), "{args:?} was accepted");
+        assert!(
+            stderr(&output).contains(message),
+            "{args:?}:\n{}",
+            stderr(&output)
+        );
+    }
+    for args in [
+        &["run", "21", "--input", "garden", "--param", "far_steps=4"][..],
+        &[
+            "run",
+            "21",
+            "--part",
+            "1",
+            "--input",
+            "garden",
+            "--param",
+            "far_steps=2",
+        ],
+    ] {
+        let output = aoc(dir.path(), None, args);
+        assert!(output.status.success(), "{args:?}: {}", stderr(&output));
+    }
+}
+
+#[test]
+fn last_day_has_one_part_and_notes_its_cut() {
+    let dir = Workspace::new("run-last-day");
+    fs::write(dir.join("wiring"), "a: b c\nb: c\nc: d\nd: e f\ne: f\n").unwrap();
+
+    let output = aoc(dir.path(), None, &["run", "25", "--input", "wiring"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "9\n");
+
+    let output = aoc(
+        dir.path(),
+        None,
+        &["run", "25", "--input", "wiring", "--format", "json"],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    let records: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(records.as_array().unwrap().len(), 1);
+    assert_eq!(records[0]["answer"], 9);
+    assert_eq!(records[0]["notes"], serde_json::json!(["c/d"]));
+
+    let output = aoc(
+        dir.path(),
+        None,
+        &["run", "25", "--input", "wiring", "--part", "2"],
+    );
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("day 25 part 2: no answer: there is no second part"),
+        "{}",
+        stderr(&output)
+    );
+}
@@ -0,0 +1,166 @@
+mod common;
+
+use std::{fs, process::Output};
+
+use common::{aoc, stderr, stdout, Stub, Workspace};
+
+/// Day 1's example, whose part 1 answer is 142.
+const INPUT: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+fn page(message: &str) -> String {
+    format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+}
+
+fn workspace(name: &str, history: &str) -> Workspace {
+    let dir = Workspace::new(name);
+    fs::create_dir_all(dir.join("day1")).unwrap();
+    fs::write(dir.join("day1/input"), INPUT).unwrap();
+    if !history.is_empty() {
+        fs::write(dir.join("day1/submissions.jsonl"), history).unwrap();
+    }
+    dir
+}
+
+fn submit(stub: &Stub, dir: &Workspace) -> Output {
+    aoc(
+        dir.path(),
+        Some("secret"),
+        &[
+            "submit",
+            "1",
+            "1",
+            "--base-url",
+            &stub.base_url,
+            "--min-interval",
+            "0",
+        ],
+    )
+}
+
+fn history(dir: &Workspace) -> String {
+    fs::read_to_string(dir.join("day1/submissions.jsonl")).unwrap_or_default()
+}
+
+#[test]
+fn posts_the_computed_answer() {
+    let stub = Stub::start(|_| {
+        (
+            200,
+            page("That's the right answer! You are one gold star closer."),
+        )
+    });
+    let dir = workspace("submit-correct", "");
+
+    let output = submit(&stub, &dir);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(
+        stdout(&output).contains("142 is correct"),
+        "{}",
+        stdout(&output)
+    );
+    let received = stub.received();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].method, "POST");
+    assert_eq!(received[0].url, "/2023/day/1/answer");
+    assert_eq!(received[0].cookie.as_deref(), Some("session=secret"));
+    assert_eq!(received[0].body, "level=1&answer=142");
+    assert!(history(&dir).contains(r#""answer":"142","outcome":"correct""#));
+
+    // Already accepted: nothing more is sent.
+    let output = submit(&stub, &dir);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stub.received().len(), 1);
+}
+
+#[test]
+fn never_resubmits_a_rejected_answer() {
+    let stub = Stub::start(|_| {
+        (
+            200,
+            page("That's not the right answer; your answer is too high. Please wait one minute."),
+        )
+    });
+    let dir = workspace("submit-too-high", "");
+
+    let output = submit(&stub, &dir);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("142 is too high"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(history(&dir).contains(r#""outcome":"too_high""#));
+
+    let output = submit(&stub, &dir);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("already rejected"),
+        "{}",
+        stderr(&output)
+    );
+    assert_eq!(stub.received().len(), 1);
+}
+
+#[test]
+fn refuses_answers_outside_known_bounds() {
+    let stub = Stub::start(|_| (200, page("That's the right answer!")));
+    let dir = workspace(
+        "submit-bounds",
+        "{\"time\":0,\"part\":1,\"answer\":\"500\",\"outcome\":\"too_low\"}\n",
+    );
+
+    let output = submit(&stub, &dir);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("500 was already too low"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(stub.received().is_empty());
+}
+
+#[test]
+fn reports_how_long_to_wait() {
+    let stub = Stub::start(|_| {
+        (
+            200,
+            page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again. You have 1m 30s left to wait.",
+            ),
+        )
+    });
+    let dir = workspace("submit-wait", "");
+
+    let output = submit(&stub, &dir);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("wait 90s"), "{}", stderr(&output));
+    assert!(history(&dir).contains(r#""outcome":"wait","wait_seconds":90"#));
+
+    // A rate-limited attempt was never checked, so it may be sent again.
+    submit(&stub, &dir);
+    assert_eq!(stub.received().len(), 2);
+}
+
+#[test]
+fn recognises_an_already_solved_part() {
+    let stub = Stub::start(|_| {
+        (
+            200,
+            page("You don't seem to be solving the right level. Did you already complete it?"),
+        )
+    });
+    let dir = workspace("submit-solved", "");
+
+    let output = submit(&stub, &dir);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("already solved"),
+        "{}",
+        stderr(&output)
+    );
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use std::fs;
+
+use common::{aoc, stderr, stdout, Workspace};
+
+fn day9(name: &str, output: &str) -> Workspace {
+    let dir = Workspace::new(name);
+    fs::create_dir(dir.join("day9")).unwrap();
+    fs::write(dir.join("day9/input"), "0 3 6 9 12 15\n").unwrap();
+    fs::write(dir.join("day9/output"), output).unwrap();
+    dir
+}
+
+#[test]
+fn reads_labelled_answers_from_output() {
+    let dir = day9("verify-labelled", "some trace\npart1: 18\npart2: -4\n");
+
+    let output = aoc(dir.path(), None, &["verify", "9"]);
+
+    assert!(!output.status.success());
+    let stdout = stdout(&output);
+    assert!(stdout.contains("9  part 1  PASS"), "{stdout}");
+    assert!(
+        stdout.contains("9  part 2  FAIL     expected -4, got -3"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn ignores_output_without_labelled_answers() {
+    let dir = day9("verify-unlabelled", "18\n-3\n");
+
+    let output = aoc(dir.path(), None, &["verify", "9"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let stdout = stdout(&output);
+    assert!(
+        stdout.contains("9  part 1  MISSING  no snapshot"),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("9  part 2  MISSING  no snapshot"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn the_last_day_has_no_part_2_to_verify() {
+    let dir = Workspace::new("verify-last-day");
+    fs::create_dir(dir.join("day25")).unwrap();
+    fs::write(
+        dir.join("day25/input"),
+        "a: b c\nb: c\nc: d\nd: e f\ne: f\n",
+    )
+    .unwrap();
+    fs::write(dir.join("day25/output"), "part1: 9\n").unwrap();
+
+    let output = aoc(dir.path(), None, &["verify", "25"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let stdout = stdout(&output);
+    assert!(stdout.contains("25  part 1  PASS"), "{stdout}");
+    assert!(stdout.contains("25  part 2  n/a"), "{stdout}");
+}
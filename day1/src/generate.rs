@@ -0,0 +1,39 @@
+//! Random calibration documents.
+
+use aoc_core::generate::{word, Generator, IndexedRandom, RngExt, StdRng};
+
+use crate::WORDS;
+
+/// Sized by the number of lines.
+pub const GENERATOR: Generator = Generator {
+    size: 1000,
+    input,
+    answers: None,
+};
+
+/// Lines mixing letters, digits and spelled-out digits, each with at least
+/// one digit so that part 1 has something to read.
+fn input(rng: &mut StdRng, lines: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..lines {
+        let tokens = rng.random_range(1..=8);
+        let digit_at = rng.random_range(0..tokens);
+        for i in 0..tokens {
+            let kind = if i == digit_at {
+                0
+            } else {
+                rng.random_range(0..3)
+            };
+            match kind {
+                0 => s.push(char::from(rng.random_range(b'1'..=b'9'))),
+                1 => s.push_str(WORDS.choose(rng).unwrap()),
+                _ => {
+                    let len = rng.random_range(1..=5);
+                    s.push_str(&word(rng, len));
+                }
+            }
+        }
+        s.push('\n');
+    }
+    s
+}
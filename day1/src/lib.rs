@@ -0,0 +1,89 @@
+//! Day 1: Trebuchet?!
+
+pub mod generate;
+
+use std::str::FromStr;
+
+use aoc_core::{checked::CheckedIterator, Answer, Overflow, ParseError, PartError, Solution};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The number formed by the first and last of `digits`.
+fn calibration(digits: &[u32]) -> u64 {
+    digits
+        .first()
+        .zip(digits.last())
+        .map_or(0, |(first, last)| u64::from(first * 10 + last))
+}
+
+pub fn part1(lines: &[String]) -> Result<u64, Overflow> {
+    lines
+        .iter()
+        .map(|line| {
+            let digits: Vec<u32> = line.chars().filter_map(|c| c.to_digit(10)).collect();
+            calibration(&digits)
+        })
+        .checked_sum()
+}
+
+pub fn part2(lines: &[String]) -> Result<u64, Overflow> {
+    lines
+        .iter()
+        .map(|line| {
+            let digits: Vec<u32> = line
+                .char_indices()
+                .filter_map(|(n, c)| {
+                    let spelled = WORDS.iter().position(|word| line[n..].starts_with(word));
+                    spelled.map(|i| i as u32 + 1).or_else(|| c.to_digit(10))
+                })
+                .collect();
+            calibration(&digits)
+        })
+        .checked_sum()
+}
+
+pub struct Input {
+    pub lines: Vec<String>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s
+            .lines()
+            .map(|line| {
+                let spelled = WORDS.iter().any(|word| line.contains(word));
+                if spelled || line.chars().any(|c| c.is_ascii_digit()) {
+                    Ok(line.to_owned())
+                } else {
+                    Err(ParseError::expected(
+                        "a line with a digit, in figures or spelled out",
+                        line,
+                    ))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Input { lines })
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(&input.lines)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(&input.lines)?.into())
+    }
+}
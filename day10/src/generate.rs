@@ -0,0 +1,54 @@
+//! Random pipe mazes.
+
+use std::collections::HashMap;
+
+use aoc_core::{
+    generate::{self, Generator, IndexedRandom, RngExt, StdRng},
+    geometry::Pos,
+};
+
+/// Sized by the side of the maze.
+pub const GENERATOR: Generator = Generator {
+    size: 140,
+    input,
+    answers: None,
+};
+
+/// The pipe joining the neighbours of `pos` at `a` and `b`.
+fn pipe(pos: Pos, a: Pos, b: Pos) -> char {
+    let dirs = [a - pos, b - pos].map(|d| (d.r, d.c));
+    let has = |d| dirs.contains(&d);
+    match (has((-1, 0)), has((1, 0)), has((0, -1)), has((0, 1))) {
+        (true, true, _, _) => '|',
+        (_, _, true, true) => '-',
+        (true, _, _, true) => 'L',
+        (true, _, true, _) => 'J',
+        (_, true, true, _) => '7',
+        _ => 'F',
+    }
+}
+
+/// A square maze whose main loop may run along any edge, with the start at
+/// a random point of the loop and junk pipes everywhere else. The tiles
+/// next to the start that are not on the loop are ground, so that no junk
+/// pipe connects to it.
+fn input(rng: &mut StdRng, side: usize) -> String {
+    let side = side.max(2);
+    let points = generate::random_loop(rng, side, side, 0.5);
+    let n = points.len();
+    let mut pipes: HashMap<Pos, char> = (0..n)
+        .map(|i| {
+            let pos = points[i];
+            (pos, pipe(pos, points[(i + n - 1) % n], points[(i + 1) % n]))
+        })
+        .collect();
+    let start = rng.random_range(0..n);
+    pipes.insert(points[start], 'S');
+    for pos in points[start].neighbours4() {
+        pipes.entry(pos).or_insert('.');
+    }
+    generate::grid(side, side, |pos| match pipes.get(&pos) {
+        Some(&pipe) => pipe,
+        None => *['|', '-', 'L', 'J', '7', 'F', '.'].choose(rng).unwrap(),
+    })
+}
@@ -0,0 +1,148 @@
+//! Day 10: Pipe Maze
+
+pub mod generate;
+pub mod naive;
+
+use std::{str::FromStr, vec};
+
+use aoc_core::{
+    geometry::Pos, grid::Grid, polygon::Polygon, Answer, ParseError, PartError, Solution,
+};
+
+pub struct Input {
+    pub mtx: Grid<char>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { mtx: s.parse()? })
+    }
+}
+
+pub fn neighbors(mtx: &Grid<char>, pos: Pos) -> Vec<Pos> {
+    let (r, c) = (pos.r, pos.c);
+    match mtx[pos] {
+        'S' => vec![(r, c + 1), (r, c - 1), (r + 1, c), (r - 1, c)],
+        '-' => vec![(r, c + 1), (r, c - 1)],
+        '|' => vec![(r + 1, c), (r - 1, c)],
+        'L' => vec![(r, c + 1), (r - 1, c)],
+        'J' => vec![(r, c - 1), (r - 1, c)],
+        '7' => vec![(r, c - 1), (r + 1, c)],
+        'F' => vec![(r, c + 1), (r + 1, c)],
+        _ => vec![],
+    }
+    .into_iter()
+    .map(|(r, c)| Pos { r, c })
+    .filter(|&pos| mtx.contains(pos))
+    .collect()
+}
+
+fn advance(mtx: &Grid<char>, curr: Pos, next: Pos) -> Option<Pos> {
+    if !neighbors(mtx, curr).contains(&next) {
+        return None;
+    }
+    let next_neighbors = neighbors(mtx, next);
+    if !next_neighbors.contains(&curr) || next_neighbors.len() != 2 {
+        return None;
+    }
+    next_neighbors.into_iter().find(|&pos| pos != curr)
+}
+
+pub fn traverse(
+    mtx: &Grid<char>,
+    mut curr: Pos,
+    mut next: Pos,
+    pred: fn(char) -> bool,
+) -> Option<usize> {
+    let mut length = 0;
+    loop {
+        if let Some(next_next) = advance(mtx, curr, next) {
+            length += 1;
+            curr = next;
+            next = next_next;
+            if pred(mtx[next]) {
+                return Some(length);
+            }
+        } else {
+            return None;
+        }
+    }
+}
+
+pub fn start(mtx: &Grid<char>) -> Result<Pos, PartError> {
+    mtx.position(|&ch| ch == 'S')
+        .ok_or_else(|| PartError::no_answer("there is no start tile `S`"))
+}
+
+pub fn part1(input: &Input) -> Result<usize, PartError> {
+    let start = start(&input.mtx)?;
+    let neighbors = neighbors(&input.mtx, start);
+    for next in neighbors {
+        if let Some(length) = traverse(&input.mtx, start, next, |ch| ch == 'S') {
+            return Ok(length.div_ceil(2));
+        }
+    }
+    Ok(0)
+}
+
+pub fn traverse_visit(
+    mtx: &Grid<char>,
+    mut curr: Pos,
+    mut next: Pos,
+    pred: fn(char) -> bool,
+    mut visit: impl FnMut(char, Pos),
+) -> bool {
+    loop {
+        if let Some(next_next) = advance(mtx, curr, next) {
+            curr = next;
+            next = next_next;
+            visit(mtx[curr], curr);
+            if pred(mtx[next]) {
+                return true;
+            }
+        } else {
+            return false;
+        }
+    }
+}
+
+pub fn part2(input: &Input) -> Result<i128, PartError> {
+    let start = start(&input.mtx)?;
+    let neighbors = neighbors(&input.mtx, start);
+    for next in neighbors {
+        let mut polygon: Vec<Pos> = Vec::new();
+        if traverse_visit(
+            &input.mtx,
+            start,
+            next,
+            |ch| ch == 'S',
+            |_, pos| {
+                polygon.push(pos);
+            },
+        ) {
+            polygon.push(start);
+            return Ok(Polygon::new(polygon).interior_points());
+        }
+    }
+    Ok(0)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
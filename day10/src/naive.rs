@@ -0,0 +1,64 @@
+//! Straightforward solutions to check the optimised ones against.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_core::{geometry::Pos, grid::Grid};
+
+use crate::{neighbors, Input};
+
+/// Whether the pipes at `a` and `b` connect to each other.
+fn connected(mtx: &Grid<char>, a: Pos, b: Pos) -> bool {
+    neighbors(mtx, a).contains(&b) && neighbors(mtx, b).contains(&a)
+}
+
+/// Distances from the start to every tile of the loop, by breadth-first
+/// search along connected pipes.
+fn distances(mtx: &Grid<char>) -> HashMap<Pos, usize> {
+    let start = mtx.position(|&ch| ch == 'S').unwrap();
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(pos) = queue.pop_front() {
+        for next in neighbors(mtx, pos) {
+            if connected(mtx, pos, next) && !distances.contains_key(&next) {
+                distances.insert(next, distances[&pos] + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+pub fn part1(input: &Input) -> usize {
+    distances(&input.mtx).into_values().max().unwrap()
+}
+
+/// Flood-fills the outside of the loop on a map of twice the resolution,
+/// where the gaps between tiles become tiles of their own so that the fill
+/// can squeeze between pipes, and counts the tiles it does not reach.
+pub fn part2(input: &Input) -> usize {
+    let mtx = &input.mtx;
+    let on_loop: HashSet<Pos> = distances(mtx).into_keys().collect();
+    // Tile (r, c) is at (2r + 1, 2c + 1), leaving a free border all round.
+    let fine = |pos: Pos| Pos::new(2 * pos.r + 1, 2 * pos.c + 1);
+    let mut walls = Grid::filled(2 * mtx.width() + 1, 2 * mtx.height() + 1, false);
+    for &pos in &on_loop {
+        walls[fine(pos)] = true;
+        for next in neighbors(mtx, pos) {
+            if connected(mtx, pos, next) {
+                walls[pos + next + Pos::new(1, 1)] = true;
+            }
+        }
+    }
+    let mut outside = HashSet::from([Pos::new(0, 0)]);
+    let mut queue = VecDeque::from([Pos::new(0, 0)]);
+    while let Some(pos) = queue.pop_front() {
+        for next in walls.neighbours4(pos) {
+            if !walls[next] && outside.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    mtx.positions()
+        .filter(|pos| !on_loop.contains(pos) && !outside.contains(&fine(*pos)))
+        .count()
+}
@@ -0,0 +1,20 @@
+use day10::{generate::GENERATOR, naive, Input};
+
+#[test]
+fn picks_theorem_matches_flood_fill() {
+    for seed in 0..30 {
+        let side = 2 + seed as usize;
+        let text = GENERATOR.generate(seed, side);
+        let input: Input = text.parse().unwrap();
+        assert_eq!(
+            day10::part1(&input),
+            Ok(naive::part1(&input)),
+            "seed {seed}:\n{text}"
+        );
+        assert_eq!(
+            day10::part2(&input),
+            Ok(naive::part2(&input) as i128),
+            "seed {seed}:\n{text}"
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_core::PartError;
+use day10::Input;
+
+#[test]
+fn the_maze_needs_a_start() {
+    let input: Input = ".F7\n.LJ\n".parse().unwrap();
+    let no_start = PartError::no_answer("there is no start tile `S`");
+    assert_eq!(day10::part1(&input), Err(no_start.clone()));
+    assert_eq!(day10::part2(&input), Err(no_start));
+}
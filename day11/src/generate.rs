@@ -0,0 +1,25 @@
+//! Random images of the sky.
+
+use aoc_core::generate::{self, Generator, RngExt, StdRng};
+
+/// Sized by the side of the image.
+pub const GENERATOR: Generator = Generator {
+    size: 140,
+    input,
+    answers: None,
+};
+
+/// A square image with a few empty rows and columns to expand and galaxies
+/// scattered over the rest.
+fn input(rng: &mut StdRng, side: usize) -> String {
+    let empty_rows: Vec<bool> = (0..side).map(|_| rng.random_bool(0.07)).collect();
+    let empty_cols: Vec<bool> = (0..side).map(|_| rng.random_bool(0.07)).collect();
+    generate::grid(side, side, |pos| {
+        let empty = empty_rows[pos.r as usize] || empty_cols[pos.c as usize];
+        if !empty && rng.random_bool(0.025) {
+            '#'
+        } else {
+            '.'
+        }
+    })
+}
@@ -0,0 +1,106 @@
+//! Day 11: Cosmic Expansion
+
+pub mod generate;
+
+use std::{collections::BTreeSet, str::FromStr};
+
+use aoc_core::{
+    checked, geometry::Pos, grid::Grid, params::Params, Answer, Overflow, ParseError, PartError,
+    Solution,
+};
+
+/// How many times larger each empty row and column becomes in part 2.
+const EXPANSION: usize = 1000000;
+
+pub struct Input {
+    pub mtx: Grid<char>,
+    pub expansion: usize,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            mtx: s.parse()?,
+            expansion: EXPANSION,
+        })
+    }
+}
+
+pub fn distance(
+    p1: Pos,
+    p2: Pos,
+    empty_rows: &BTreeSet<isize>,
+    empty_cols: &BTreeSet<isize>,
+    extend_rate: usize,
+) -> Result<usize, Overflow> {
+    let rows = p1.r.min(p2.r)..p1.r.max(p2.r);
+    let cols = p1.c.min(p2.c)..p1.c.max(p2.c);
+
+    let empty = empty_rows.range(rows).count() + empty_cols.range(cols).count();
+    checked::add(p1.manhattan(p2), checked::mul(empty, extend_rate)?)
+}
+
+pub fn solve(input: &Input, extend_rate: usize) -> Result<usize, Overflow> {
+    let mtx = &input.mtx;
+
+    let galaxies = mtx
+        .iter()
+        .filter(|(_, &ch)| ch == '#')
+        .map(|(pos, _)| pos)
+        .collect::<Vec<_>>();
+
+    let empty_rows = (0..mtx.height())
+        .filter(|&r| mtx.row(r).iter().all(|&ch| ch == '.'))
+        .map(|r| r as isize)
+        .collect::<BTreeSet<_>>();
+    let empty_cols = (0..mtx.width())
+        .filter(|&c| mtx.column(c).all(|&ch| ch == '.'))
+        .map(|c| c as isize)
+        .collect::<BTreeSet<_>>();
+
+    let mut total_distance = 0;
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            let distance = distance(
+                galaxies[i],
+                galaxies[j],
+                &empty_rows,
+                &empty_cols,
+                extend_rate,
+            )?;
+            total_distance = checked::add(total_distance, distance)?;
+        }
+    }
+    Ok(total_distance)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input, ParseError> {
+        let mut input: Input = input.parse()?;
+        input.expansion = params.get("expansion", EXPANSION)?;
+        if input.expansion == 0 {
+            return Err(ParseError::new(
+                "parameter `expansion` must be at least 1, the size of an empty row",
+            ));
+        }
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(solve(input, 1)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(solve(input, input.expansion - 1)?.into())
+    }
+}
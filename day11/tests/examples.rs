@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_core::examples::check::<day11::Day11>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,39 @@
+//! Random condition records.
+
+use aoc_core::generate::{Generator, RngExt, StdRng};
+
+/// Sized by the number of records.
+pub const GENERATOR: Generator = Generator {
+    size: 1000,
+    input,
+    answers: None,
+};
+
+/// Records of up to 20 springs, with about half of the conditions unknown.
+/// The groups are those of a random arrangement, so every record has at
+/// least one.
+fn input(rng: &mut StdRng, records: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..records {
+        let len = rng.random_range(1..=20);
+        let mut springs: Vec<bool> = (0..len).map(|_| rng.random_bool(0.5)).collect();
+        if !springs.contains(&true) {
+            springs[rng.random_range(0..len)] = true;
+        }
+        let groups: Vec<String> = springs
+            .split(|&damaged| !damaged)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        let row: String = springs
+            .iter()
+            .map(|&damaged| match (rng.random_bool(0.5), damaged) {
+                (true, _) => '?',
+                (false, true) => '#',
+                (false, false) => '.',
+            })
+            .collect();
+        s.push_str(&format!("{row} {}\n", groups.join(",")));
+    }
+    s
+}
@@ -0,0 +1,164 @@
+//! Day 12: Hot Springs
+
+pub mod generate;
+
+use std::str::FromStr;
+
+use cached::proc_macro::cached;
+
+use aoc_core::{
+    checked::{self, CheckedIterator},
+    params::Params,
+    parse, Answer, Overflow, ParseError, PartError, Solution,
+};
+
+pub struct InputLine {
+    pub pattern: String,
+    pub numbers: Vec<usize>,
+}
+/// How many copies of each row are joined when unfolding in part 2.
+const FOLDS: usize = 5;
+
+pub struct Input {
+    pub input_lines: Vec<InputLine>,
+    pub folds: usize,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input_lines = s
+            .lines()
+            .map(|line| {
+                let (row, numbers) = parse::split_once(line, " ")?;
+                if let Some(i) = row.find(|ch| !matches!(ch, '.' | '#' | '?')) {
+                    let ch = row[i..].chars().next().unwrap();
+                    return Err(ParseError::expected(
+                        "`.`, `#` or `?`",
+                        &row[i..i + ch.len_utf8()],
+                    ));
+                }
+                let numbers = numbers
+                    .split(',')
+                    .map(|x| match parse::number(x)? {
+                        0 => Err(ParseError::expected("a group of at least one spring", x)),
+                        n => Ok(n),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(InputLine {
+                    pattern: row.to_owned(),
+                    numbers,
+                })
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        Ok(Self {
+            input_lines,
+            folds: FOLDS,
+        })
+    }
+}
+
+/// Number of ways to fill the `?`s in `pattern` so that its runs of `#` match `numbers`.
+#[cached]
+pub fn num_combinations(pattern: String, mut numbers: Vec<usize>) -> Result<usize, Overflow> {
+    if numbers.is_empty() {
+        return Ok(
+            if pattern.is_empty() || pattern.chars().all(|ch| ch == '.' || ch == '?') {
+                1
+            } else {
+                0
+            },
+        );
+    }
+    let last_len = numbers.pop().unwrap();
+    let last = "#".repeat(last_len);
+    // The other groups and the dots between them come first.
+    let before = (numbers.iter().sum::<usize>() + numbers.len()).saturating_sub(1);
+    let separator = usize::from(!numbers.is_empty());
+    let Some(max_dots) = pattern.len().checked_sub(before + separator + last_len) else {
+        return Ok(0);
+    };
+
+    let mut total_combinations = 0;
+    for dot_count in 0..=max_dots {
+        let last_dots = ".".repeat(dot_count);
+        let suffix = format!(
+            "{}{}{}",
+            if numbers.is_empty() { "" } else { "." },
+            last,
+            last_dots
+        );
+        if does_match(suffix.as_str(), &pattern[pattern.len() - suffix.len()..]) {
+            let combinations = num_combinations(
+                pattern[..pattern.len() - suffix.len()].to_owned(),
+                numbers.clone(),
+            )?;
+            total_combinations = checked::add(total_combinations, combinations)?;
+        }
+    }
+    Ok(total_combinations)
+}
+
+fn does_match(row: &str, pattern: &str) -> bool {
+    let row = row.as_bytes();
+    let pattern = pattern.as_bytes();
+    assert!(row.len() == pattern.len());
+    row.iter()
+        .zip(pattern)
+        .all(|(&row, &pattern)| pattern == b'?' || pattern == row)
+}
+
+pub fn part1(input: &Input) -> Result<usize, Overflow> {
+    input
+        .input_lines
+        .iter()
+        .map(|InputLine { pattern, numbers }| num_combinations(pattern.clone(), numbers.clone()))
+        .try_checked_sum()
+}
+
+pub fn part2(input: &Input) -> Result<usize, Overflow> {
+    input
+        .input_lines
+        .iter()
+        .map(|InputLine { pattern, numbers }| {
+            let pattern = std::iter::repeat_n(pattern.as_str(), input.folds)
+                .collect::<Vec<_>>()
+                .join("?");
+            let numbers = std::iter::repeat_n(numbers, input.folds)
+                .flatten()
+                .copied()
+                .collect::<Vec<usize>>();
+            num_combinations(pattern, numbers)
+        })
+        .try_checked_sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input, ParseError> {
+        let mut input: Input = input.parse()?;
+        input.folds = params.get("folds", FOLDS)?;
+        if input.folds == 0 {
+            return Err(ParseError::new(
+                "parameter `folds` must be at least 1, the row itself",
+            ));
+        }
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
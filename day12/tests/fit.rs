@@ -0,0 +1,8 @@
+use day12::num_combinations;
+
+#[test]
+fn groups_that_do_not_fit_have_no_arrangement() {
+    assert_eq!(num_combinations("??".to_owned(), vec![1, 1, 1]), Ok(0));
+    assert_eq!(num_combinations("???".to_owned(), vec![1, 2]), Ok(0));
+    assert_eq!(num_combinations("????".to_owned(), vec![1, 2]), Ok(1));
+}
@@ -0,0 +1,135 @@
+//! Random patterns of ash and rocks.
+
+use aoc_core::{
+    generate::{Generator, RngExt, StdRng},
+    Answer,
+};
+
+/// Sized by the number of patterns.
+pub const GENERATOR: Generator = Generator {
+    size: 100,
+    input,
+    answers: Some(answers),
+};
+
+/// Cells that differ from their reflection across the line before column
+/// `col`, for every column but the first.
+fn errors(pattern: &[Vec<bool>], col: usize) -> usize {
+    let width = pattern[0].len();
+    let span = col.min(width - col);
+    pattern
+        .iter()
+        .map(|row| {
+            (0..span)
+                .filter(|&i| row[col - 1 - i] != row[col + i])
+                .count()
+        })
+        .sum()
+}
+
+fn transpose(pattern: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    (0..pattern[0].len())
+        .map(|c| pattern.iter().map(|row| row[c]).collect())
+        .collect()
+}
+
+/// Lines of reflection of `pattern` with exactly `smudges` differences, as
+/// columns and then rows.
+fn reflections(pattern: &[Vec<bool>], smudges: usize) -> Vec<(bool, usize)> {
+    let rows = transpose(pattern);
+    let cols = (1..pattern[0].len())
+        .filter(|&col| errors(pattern, col) == smudges)
+        .map(|col| (true, col));
+    let rows = (1..pattern.len())
+        .filter(|&row| errors(&rows, row) == smudges)
+        .map(|row| (false, row));
+    cols.chain(rows).collect()
+}
+
+/// Mirrors `i` across the line before `line` in `0..len`, if the mirror
+/// image is in range, and returns the smaller of the two.
+fn fold(i: usize, line: usize, len: usize) -> usize {
+    match (2 * line).checked_sub(i + 1) {
+        Some(mirror) if mirror < len => i.min(mirror),
+        _ => i,
+    }
+}
+
+/// A pattern reflected across the line before column `col` and, but for
+/// one smudge, across the line before row `row`. The smudge is in a column
+/// without a mirror image, so it does not break the column's reflection.
+fn pattern(
+    rng: &mut StdRng,
+    width: usize,
+    height: usize,
+    col: usize,
+    row: usize,
+) -> Vec<Vec<bool>> {
+    let cells: Vec<Vec<bool>> = (0..height)
+        .map(|_| (0..width).map(|_| rng.random_bool(0.5)).collect())
+        .collect();
+    let mut pattern: Vec<Vec<bool>> = (0..height)
+        .map(|r| {
+            (0..width)
+                .map(|c| cells[fold(r, row, height)][fold(c, col, width)])
+                .collect()
+        })
+        .collect();
+    let span = col.min(width - col);
+    let unmirrored: Vec<usize> = (0..width)
+        .filter(|&c| c + span < col || c >= col + span)
+        .collect();
+    let r = rng.random_range(row.saturating_sub(row.min(height - row))..row);
+    let c = unmirrored[rng.random_range(0..unmirrored.len())];
+    pattern[r][c] = !pattern[r][c];
+    pattern
+}
+
+fn input(rng: &mut StdRng, patterns: usize) -> String {
+    notes(rng, patterns).0
+}
+
+/// Each part sums the line of reflection of every pattern, before and after
+/// cleaning its smudge.
+fn answers(rng: &mut StdRng, patterns: usize) -> [Option<Answer>; 2] {
+    notes(rng, patterns).1.map(|sum| Some(sum.into()))
+}
+
+/// Patterns of 5 to 17 rows and columns, each with a single line of
+/// reflection and a single line of reflection once its smudge is cleaned,
+/// and the summaries of both. Either line may be a row or a column.
+fn notes(rng: &mut StdRng, patterns: usize) -> (String, [usize; 2]) {
+    let mut maps = vec![];
+    let mut sums = [0, 0];
+    while maps.len() < patterns {
+        let (width, height) = (rng.random_range(5..=17), rng.random_range(5..=17));
+        let col = rng.random_range(1..width);
+        if 2 * col == width {
+            continue;
+        }
+        let row = rng.random_range(1..height);
+        let mut pattern = pattern(rng, width, height, col, row);
+        if reflections(&pattern, 0) != [(true, col)] || reflections(&pattern, 1) != [(false, row)] {
+            continue;
+        }
+        if rng.random_bool(0.5) {
+            pattern = transpose(&pattern);
+            sums[0] += 100 * col;
+            sums[1] += row;
+        } else {
+            sums[0] += col;
+            sums[1] += 100 * row;
+        }
+        let map: String = pattern
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&rock| if rock { '#' } else { '.' })
+                    .chain(['\n'])
+                    .collect::<String>()
+            })
+            .collect();
+        maps.push(map);
+    }
+    (maps.join("\n"), sums)
+}
@@ -0,0 +1,110 @@
+//! Day 13: Point of Incidence
+
+pub mod generate;
+
+use std::{
+    iter::{Rev, Take},
+    ops::Range,
+    str::FromStr,
+};
+
+use aoc_core::{checked, grid::Grid, Answer, ParseError, PartError, Solution};
+
+pub type Map = Grid<char>;
+
+pub struct Input {
+    pub maps: Vec<Map>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        let maps = lines
+            .split(|line| line.is_empty())
+            .filter(|block| !block.is_empty())
+            .map(|block| Grid::from_lines(block, |ch, _| Ok(ch)))
+            .collect::<Result<Vec<Map>, _>>()?;
+        if maps.is_empty() {
+            return Err(ParseError::expected("a pattern", &s[s.len()..]));
+        }
+        Ok(Self { maps })
+    }
+}
+
+fn rows_equal_error(
+    map: &Map,
+    top_range: Take<Rev<Range<usize>>>,
+    bottom_range: Take<Range<usize>>,
+) -> usize {
+    top_range
+        .zip(bottom_range)
+        .map(|(top, bottom)| {
+            map.row(top)
+                .iter()
+                .zip(map.row(bottom))
+                .filter(|(top, bottom)| top != bottom)
+                .count()
+        })
+        .sum::<usize>()
+}
+
+pub fn find_vertical_symmetry(map: &Map, error: usize) -> Option<usize> {
+    find_horizontal_symmetry(&map.transpose(), error)
+}
+
+pub fn find_horizontal_symmetry(map: &Map, error: usize) -> Option<usize> {
+    let row_max = map.height();
+    (1..row_max).find(|&row| {
+        let bottom_range = row..row_max;
+        let top_range = (0..row).rev();
+        let common_length = bottom_range.len().min(top_range.len());
+        let bottom_range = bottom_range.take(common_length);
+        let top_range = top_range.take(common_length);
+        rows_equal_error(map, top_range, bottom_range) == error
+    })
+}
+
+pub fn solve(map: &Map, error: usize) -> Option<usize> {
+    find_vertical_symmetry(map, error)
+        .or_else(|| find_horizontal_symmetry(map, error).map(|row| row * 100))
+}
+
+/// Sums the lines of reflection with `error` differences of every pattern.
+fn summarize(input: &Input, error: usize) -> Result<usize, PartError> {
+    let mut total = 0;
+    for (i, map) in input.maps.iter().enumerate() {
+        let summary = solve(map, error).ok_or_else(|| {
+            PartError::no_answer(format!("pattern {} has no line of reflection", i + 1))
+        })?;
+        total = checked::add(total, summary)?;
+    }
+    Ok(total)
+}
+
+pub fn part1(input: &Input) -> Result<usize, PartError> {
+    summarize(input, 0)
+}
+
+pub fn part2(input: &Input) -> Result<usize, PartError> {
+    summarize(input, 1)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
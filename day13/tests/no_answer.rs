@@ -0,0 +1,11 @@
+use aoc_core::PartError;
+use day13::Input;
+
+#[test]
+fn every_pattern_needs_a_line_of_reflection() {
+    let input: Input = "##\n..\n\n#..\n...\n..#\n".parse().unwrap();
+    assert_eq!(
+        day13::part1(&input),
+        Err(PartError::no_answer("pattern 2 has no line of reflection"))
+    );
+}
@@ -0,0 +1,19 @@
+//! Random platforms of rocks.
+
+use aoc_core::generate::{self, Generator, RngExt, StdRng};
+
+/// Sized by the side of the platform.
+pub const GENERATOR: Generator = Generator {
+    size: 100,
+    input,
+    answers: None,
+};
+
+/// A square platform of rounded and cube-shaped rocks.
+fn input(rng: &mut StdRng, side: usize) -> String {
+    generate::grid(side, side, |_| match rng.random_range(0..10) {
+        0 | 1 => 'O',
+        2 => '#',
+        _ => '.',
+    })
+}
@@ -0,0 +1,157 @@
+//! Day 14: Parabolic Reflector Dish
+
+pub mod generate;
+
+use std::{collections::VecDeque, fmt::Debug, str::FromStr, writeln};
+
+use aoc_core::{
+    checked::CheckedIterator, cycle, geometry::Pos, grid::Grid, params::Params, Answer, Overflow,
+    ParseError, PartError, Solution,
+};
+
+pub type Mtx = Grid<char>;
+
+/// Spin cycles run in part 2.
+const SPINS: usize = 1000000000;
+
+pub struct Input {
+    pub mtx: Mtx,
+    pub spins: usize,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            mtx: Grid::of_tiles(s, ".#O")?,
+            spins: SPINS,
+        })
+    }
+}
+
+impl Debug for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.mtx)
+    }
+}
+
+fn move_towards(pos: Pos, dest: Pos) -> Pos {
+    if pos.r == dest.r {
+        Pos {
+            r: pos.r,
+            c: if dest.c > pos.c { pos.c + 1 } else { pos.c - 1 },
+        }
+    } else {
+        Pos {
+            r: if dest.r > pos.r { pos.r + 1 } else { pos.r - 1 },
+            c: pos.c,
+        }
+    }
+}
+
+/// Rolls the round rocks on the line from `from` to `towards` as far
+/// towards it as they go.
+fn move_rocks(mtx: &mut Mtx, towards: Pos, from: Pos) {
+    let mut empty_slots = VecDeque::new();
+    let mut pos = towards;
+    while pos != from {
+        match mtx[pos] {
+            '#' => empty_slots.clear(),
+            '.' => empty_slots.push_back(pos),
+            'O' => {
+                if let Some(p_swap) = empty_slots.pop_front() {
+                    mtx.swap(pos, p_swap);
+                    empty_slots.push_back(pos);
+                }
+            }
+            _ => unreachable!("the parser only keeps `.`, `#` and `O`"),
+        }
+        pos = move_towards(pos, from);
+    }
+}
+
+pub fn move_rocks_up(mtx: &mut Mtx) {
+    let rows = mtx.height() as isize;
+    let cols = mtx.width() as isize;
+    for c in 0..cols {
+        move_rocks(mtx, Pos { r: 0, c }, Pos { r: rows, c });
+    }
+}
+
+pub fn move_rocks_down(mtx: &mut Mtx) {
+    let rows = mtx.height() as isize;
+    let cols = mtx.width() as isize;
+    for c in 0..cols {
+        move_rocks(mtx, Pos { r: rows - 1, c }, Pos { r: -1, c });
+    }
+}
+
+pub fn move_rocks_left(mtx: &mut Mtx) {
+    let rows = mtx.height() as isize;
+    let cols = mtx.width() as isize;
+    for r in 0..rows {
+        move_rocks(mtx, Pos { r, c: 0 }, Pos { r, c: cols });
+    }
+}
+
+pub fn move_rocks_right(mtx: &mut Mtx) {
+    let rows = mtx.height() as isize;
+    let cols = mtx.width() as isize;
+    for r in 0..rows {
+        move_rocks(mtx, Pos { r, c: cols - 1 }, Pos { r, c: -1 });
+    }
+}
+
+pub fn part1(input: &Input) -> Result<isize, Overflow> {
+    let mut mtx = input.mtx.clone();
+    move_rocks_up(&mut mtx);
+    north_load(&mtx)
+}
+
+/// Total load on the north support beams.
+pub fn north_load(mtx: &Mtx) -> Result<isize, Overflow> {
+    let rows = mtx.height() as isize;
+    mtx.iter()
+        .filter(|&(_, &ch)| ch == 'O')
+        .map(|(pos, _)| rows - pos.r)
+        .checked_sum()
+}
+
+/// One spin cycle: tilt north, west, south, then east.
+pub fn spin(mtx: &Mtx) -> Mtx {
+    let mut mtx = mtx.clone();
+    move_rocks_up(&mut mtx);
+    move_rocks_left(&mut mtx);
+    move_rocks_down(&mut mtx);
+    move_rocks_right(&mut mtx);
+    mtx
+}
+
+pub fn part2(input: &Input) -> Result<isize, Overflow> {
+    north_load(&cycle::nth_state(input.mtx.clone(), spin, input.spins))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input, ParseError> {
+        let mut input: Input = input.parse()?;
+        input.spins = params.get("spins", SPINS)?;
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
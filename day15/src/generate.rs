@@ -0,0 +1,32 @@
+//! Random initialization sequences.
+
+use aoc_core::generate::{self, Generator, IndexedRandom, RngExt, StdRng};
+
+/// Sized by the number of steps.
+pub const GENERATOR: Generator = Generator {
+    size: 4000,
+    input,
+    answers: None,
+};
+
+/// Steps on labels of up to six letters, drawn from a pool small enough
+/// that lenses are often replaced and removed.
+fn input(rng: &mut StdRng, steps: usize) -> String {
+    let labels: Vec<String> = (0..(steps / 7).max(1))
+        .map(|_| {
+            let len = rng.random_range(1..=6);
+            generate::word(rng, len)
+        })
+        .collect();
+    let steps: Vec<String> = (0..steps)
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.random_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.random_range(1..=9))
+            }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
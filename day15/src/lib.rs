@@ -0,0 +1,128 @@
+//! Day 15: Lens Library
+
+pub mod generate;
+
+use std::{ops::IndexMut, str::FromStr};
+
+use aoc_core::{
+    checked::{self, CheckedIterator},
+    parse, Answer, Overflow, ParseError, PartError, Solution,
+};
+
+pub enum Operation {
+    Remove,
+    Insert(i64),
+}
+pub struct Step {
+    pub hash: u8,
+    pub label_hash: u8,
+    pub key: String,
+    pub operation: Operation,
+}
+
+impl TryFrom<&str> for Step {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let h = hash(value);
+        match value.split_once('=') {
+            Some((key, value)) => Ok(Self {
+                hash: h,
+                label_hash: hash(key),
+                key: key.to_owned(),
+                operation: Operation::Insert(parse::number(value)?),
+            }),
+            None => {
+                let key = parse::strip_suffix(value, "-")?.to_owned();
+                Ok(Self {
+                    hash: h,
+                    label_hash: hash(key.as_str()),
+                    key,
+                    operation: Operation::Remove,
+                })
+            }
+        }
+    }
+}
+
+pub struct Input {
+    pub steps: Vec<Step>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = s
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new("input is empty"))?;
+        Ok(Input {
+            steps: line
+                .split(',')
+                .map(Step::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+pub fn hash(step: &str) -> u8 {
+    step.bytes()
+        .fold(0, |res, curr| (res + curr as i32) * 17 % 256) as u8
+}
+
+pub fn part1(input: &[Step]) -> Result<i64, Overflow> {
+    input.iter().map(|step| i64::from(step.hash)).checked_sum()
+}
+
+pub fn part2(input: &[Step]) -> Result<i64, Overflow> {
+    let hash_table: Vec<Vec<(String, i64)>> = vec![vec![]; 256];
+    let hash_table = input.iter().fold(hash_table, |mut acc, step| {
+        let list = acc.index_mut(step.label_hash as usize);
+        match step.operation {
+            Operation::Remove => {
+                if let Some((idx, _)) = list.iter().enumerate().find(|(_, (k, _))| *k == step.key) {
+                    list.remove(idx);
+                }
+            }
+            Operation::Insert(value) => {
+                if let Some((_, v)) = list.iter_mut().find(|(k, _)| *k == step.key) {
+                    *v = value;
+                } else {
+                    list.push((step.key.clone(), value));
+                }
+            }
+        };
+        acc
+    });
+    hash_table
+        .into_iter()
+        .enumerate()
+        .map(|(list_idx, list)| {
+            let power = list
+                .into_iter()
+                .enumerate()
+                .map(|(slot_idx, (_, strength))| checked::mul((slot_idx + 1) as i64, strength))
+                .try_checked_sum()?;
+            checked::mul((list_idx + 1) as i64, power)
+        })
+        .try_checked_sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(&input.steps)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(&input.steps)?.into())
+    }
+}
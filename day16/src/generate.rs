@@ -0,0 +1,22 @@
+//! Random contraptions of mirrors and splitters.
+
+use aoc_core::generate::{self, Generator, IndexedRandom, RngExt, StdRng};
+
+/// Sized by the side of the contraption.
+pub const GENERATOR: Generator = Generator {
+    size: 110,
+    input,
+    answers: None,
+};
+
+/// A square contraption with a mirror or splitter on about a tenth of the
+/// tiles.
+fn input(rng: &mut StdRng, side: usize) -> String {
+    generate::grid(side, side, |_| {
+        if rng.random_bool(0.1) {
+            *['/', '\\', '|', '-'].choose(rng).unwrap()
+        } else {
+            '.'
+        }
+    })
+}
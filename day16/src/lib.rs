@@ -0,0 +1,129 @@
+//! Day 16: The Floor Will Be Lava
+
+pub mod generate;
+
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_core::{
+    geometry::Pos,
+    graph::{self, FnGraph},
+    grid::Grid,
+    Answer, ParseError, PartError, Solution,
+};
+
+pub type Mtx = Grid<char>;
+
+pub struct Input {
+    pub mtx: Mtx,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            mtx: Grid::of_tiles(s, ".-|/\\")?,
+        })
+    }
+}
+
+/// The beams leaving `to` after entering it from `from`.
+fn next_beams(mtx: &Mtx, from: Pos, to: Pos) -> Vec<(Pos, Pos)> {
+    let straight_ahead = to - from;
+    let turn_left = straight_ahead.counterclockwise();
+    let turn_right = straight_ahead.clockwise();
+    let dirs = match mtx[to] {
+        '.' => vec![straight_ahead],
+        '-' => {
+            if straight_ahead.r == 0 {
+                vec![straight_ahead]
+            } else {
+                vec![turn_left, turn_right]
+            }
+        }
+        '|' => {
+            if straight_ahead.c == 0 {
+                vec![straight_ahead]
+            } else {
+                vec![turn_left, turn_right]
+            }
+        }
+        '/' => {
+            if straight_ahead.r == 0 {
+                vec![turn_left]
+            } else {
+                vec![turn_right]
+            }
+        }
+        '\\' => {
+            if straight_ahead.r == 0 {
+                vec![turn_right]
+            } else {
+                vec![turn_left]
+            }
+        }
+        _ => unreachable!("the parser only keeps empty space, mirrors and splitters"),
+    };
+    dirs.into_iter()
+        .map(|dir| (to, to + dir))
+        .filter(|&(_, next)| mtx.contains(next))
+        .collect()
+}
+
+pub fn count_energy(mtx: &Mtx, start: Pos, from: Pos) -> usize {
+    let beams = FnGraph(|&(from, to): &(Pos, Pos)| {
+        next_beams(mtx, from, to).into_iter().map(|beam| (beam, 1))
+    });
+    graph::bfs(&beams, (from, start))
+        .into_keys()
+        .map(|(_, to)| to)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+pub fn part1(input: &Input) -> usize {
+    count_energy(&input.mtx, Pos { r: 0, c: 0 }, Pos { r: 0, c: -1 })
+}
+
+/// Every edge tile with the tile outside it that a beam can enter from.
+fn entries(mtx: &Mtx) -> Vec<(Pos, Pos)> {
+    let rows = mtx.height() as isize;
+    let cols = mtx.width() as isize;
+    let mut entries = vec![];
+    for c in 0..cols {
+        entries.push((Pos { r: 0, c }, Pos { r: -1, c }));
+        entries.push((Pos { r: rows - 1, c }, Pos { r: rows, c }));
+    }
+    for r in 0..rows {
+        entries.push((Pos { r, c: 0 }, Pos { r, c: -1 }));
+        entries.push((Pos { r, c: cols - 1 }, Pos { r, c: cols }));
+    }
+    entries
+}
+
+/// The most tiles any entering beam energizes.
+pub fn part2(input: &Input) -> usize {
+    entries(&input.mtx)
+        .into_iter()
+        .map(|(start, from)| count_energy(&input.mtx, start, from))
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input).into())
+    }
+}
@@ -0,0 +1,16 @@
+//! Random maps of city blocks.
+
+use aoc_core::generate::{self, Generator, RngExt, StdRng};
+
+/// Sized by the side of the map.
+pub const GENERATOR: Generator = Generator {
+    size: 141,
+    input,
+    answers: None,
+};
+
+/// A square map of the heat lost in each block, at least 5 blocks wide.
+fn input(rng: &mut StdRng, side: usize) -> String {
+    let side = side.max(5);
+    generate::grid(side, side, |_| char::from(rng.random_range(b'1'..=b'9')))
+}
@@ -0,0 +1,153 @@
+//! Day 17: Clumsy Crucible
+
+pub mod generate;
+
+use std::str::FromStr;
+
+use aoc_core::{
+    geometry::{Dir, Pos},
+    graph::{self, FnGraph, Path},
+    grid::Grid,
+    params::Params,
+    Answer, ParseError, PartError, Solution,
+};
+
+/// Heat lost on entering each block.
+pub type Mtx = Grid<u32>;
+
+/// How far a crucible may move in a straight line: at least `min` blocks
+/// before it turns or stops, and at most `max`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Runs {
+    pub min: usize,
+    pub max: usize,
+}
+
+/// Runs of a standard crucible, in part 1.
+const CRUCIBLE: Runs = Runs { min: 1, max: 3 };
+/// Runs of an ultra crucible, in part 2.
+const ULTRA_CRUCIBLE: Runs = Runs { min: 4, max: 10 };
+
+pub struct Input {
+    pub mtx: Mtx,
+    pub crucible: Runs,
+    pub ultra_crucible: Runs,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        if lines.is_empty() {
+            return Err(ParseError::expected("a row of digits", &s[s.len()..]));
+        }
+        Ok(Input {
+            mtx: Grid::from_lines(&lines, |ch, text| {
+                ch.to_digit(10)
+                    .ok_or_else(|| ParseError::expected("a digit", text))
+            })?,
+            crucible: CRUCIBLE,
+            ultra_crucible: ULTRA_CRUCIBLE,
+        })
+    }
+}
+
+/// A search state: where the crucible is, which way it last moved and how
+/// many blocks it has moved that way. `run` is 0 only before the first move.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Crucible {
+    pub pos: Pos,
+    pub dir: Dir,
+    pub run: usize,
+}
+
+/// The states reachable in one move from `state`, with the heat lost.
+fn moves(mtx: &Mtx, runs: Runs, state: &Crucible) -> Vec<(Crucible, u32)> {
+    let dirs = if state.run == 0 {
+        Dir::ALL.to_vec()
+    } else {
+        let mut dirs = vec![];
+        if state.run < runs.max {
+            dirs.push(state.dir);
+        }
+        if state.run >= runs.min {
+            dirs.extend([state.dir.turn_left(), state.dir.turn_right()]);
+        }
+        dirs
+    };
+    dirs.into_iter()
+        .filter_map(|dir| {
+            let pos = state.pos + dir;
+            let run = if dir == state.dir { state.run + 1 } else { 1 };
+            let &heat = mtx.get(pos)?;
+            Some((Crucible { pos, dir, run }, heat))
+        })
+        .collect()
+}
+
+/// The path losing the least heat from the top-left block to the
+/// bottom-right one, or `None` if the crucible cannot stop there.
+pub fn find_path(mtx: &Mtx, runs: Runs) -> Option<Path<Crucible, u32>> {
+    let start = Crucible {
+        pos: Pos::new(0, 0),
+        dir: Dir::Right,
+        run: 0,
+    };
+    let end = Pos::try_from((mtx.height().checked_sub(1)?, mtx.width().checked_sub(1)?)).ok()?;
+    graph::dijkstra(
+        &FnGraph(|state: &Crucible| moves(mtx, runs, state)),
+        start,
+        |state| state.pos == end && state.run >= runs.min,
+    )
+}
+
+pub fn least_heat_loss(mtx: &Mtx, runs: Runs) -> Result<u32, PartError> {
+    find_path(mtx, runs)
+        .map(|path| path.cost)
+        .ok_or_else(|| PartError::no_answer("the crucible cannot stop at the factory"))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input, ParseError> {
+        let mut input: Input = input.parse()?;
+        input.crucible = Runs {
+            min: params.get("min_run", CRUCIBLE.min)?,
+            max: params.get("max_run", CRUCIBLE.max)?,
+        };
+        input.ultra_crucible = Runs {
+            min: params.get("ultra_min_run", ULTRA_CRUCIBLE.min)?,
+            max: params.get("ultra_max_run", ULTRA_CRUCIBLE.max)?,
+        };
+        for (prefix, runs) in [("", input.crucible), ("ultra_", input.ultra_crucible)] {
+            if runs.max == 0 {
+                return Err(ParseError::new(format!(
+                    "parameter `{prefix}max_run` must be at least 1, a single block"
+                )));
+            }
+            if runs.min > runs.max {
+                return Err(ParseError::new(format!(
+                    "parameter `{prefix}min_run` must be at most `{prefix}max_run`, {}, got {}",
+                    runs.max, runs.min
+                )));
+            }
+        }
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(least_heat_loss(&input.mtx, input.crucible)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(least_heat_loss(&input.mtx, input.ultra_crucible)?.into())
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_core::cli::main::<day17::Day17>();
+}
@@ -0,0 +1,23 @@
+#[test]
+fn examples() {
+    aoc_core::examples::check::<day17::Day17>(env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn path_runs_from_corner_to_corner() {
+    use aoc_core::geometry::Pos;
+    use day17::{find_path, Input, Runs};
+
+    let text = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/test_input")).unwrap();
+    let Input { mtx, .. } = text.parse().unwrap();
+    let path = find_path(&mtx, Runs { min: 4, max: 10 }).unwrap();
+
+    assert_eq!(path.cost, 94);
+    assert_eq!(path.vertices.first().unwrap().pos, Pos::new(0, 0));
+    assert_eq!(path.vertices.last().unwrap().pos, Pos::new(12, 12));
+    let heat: u32 = path.vertices[1..].iter().map(|state| mtx[state.pos]).sum();
+    assert_eq!(heat, path.cost);
+    assert!(path.vertices[1..]
+        .iter()
+        .all(|state| (1..=10).contains(&state.run)));
+}
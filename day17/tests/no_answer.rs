@@ -0,0 +1,20 @@
+use aoc_core::{grid::Grid, PartError, Solution};
+use day17::{find_path, Day17, Input, Runs};
+
+#[test]
+fn ultra_crucible_cannot_stop_on_a_small_map() {
+    let input: Input = "123\n456\n789\n".parse().unwrap();
+    assert_eq!(Day17::part1(&input), Ok(20u32.into()));
+    assert!(matches!(
+        Day17::part2(&input),
+        Err(PartError::NoAnswer(reason)) if reason.contains("cannot stop at the factory")
+    ));
+}
+
+#[test]
+fn empty_maps_are_rejected_or_have_no_path() {
+    let err = "".parse::<Input>().err().unwrap();
+    assert!(err.to_string().contains("a row of digits"), "{err}");
+    let runs = Runs { min: 1, max: 3 };
+    assert!(find_path(&Grid::new(0, 0, vec![]), runs).is_none());
+}
@@ -0,0 +1,81 @@
+//! Random dig plans.
+//!
+//! A plan traces a random loop on a coarse lattice, whose gaps between
+//! rows and between columns get random widths. Both parts follow the same
+//! loop: the plan with narrow gaps, and the colours with gaps wide enough
+//! that the lagoon spans most of the range of five hex digits.
+
+use std::cmp::Ordering;
+
+use aoc_core::{
+    generate::{self, Generator, RngExt, StdRng},
+    geometry::Pos,
+};
+
+/// Sized by the number of instructions, roughly.
+pub const GENERATOR: Generator = Generator {
+    size: 700,
+    input,
+    answers: None,
+};
+
+/// The coordinate of each lattice line, after gaps of up to `max_gap`.
+fn lines(rng: &mut StdRng, count: usize, max_gap: usize) -> Vec<isize> {
+    let mut at = 0;
+    let mut lines = vec![at];
+    for _ in 1..count {
+        at += rng.random_range(1..=max_gap) as isize;
+        lines.push(at);
+    }
+    lines
+}
+
+/// The loop through `corners` of the lattice, scaled by `rows` and `cols`,
+/// as a walk of (direction, length) steps.
+fn walk(corners: &[Pos], rows: &[isize], cols: &[isize]) -> Vec<(usize, isize)> {
+    let n = corners.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (corners[i], corners[(i + 1) % n]);
+            let (a_r, a_c) = (rows[a.r as usize], cols[a.c as usize]);
+            let (b_r, b_c) = (rows[b.r as usize], cols[b.c as usize]);
+            // Directions in the order of the colours' last digit.
+            let dir = match (b_r.cmp(&a_r), b_c.cmp(&a_c)) {
+                (_, Ordering::Greater) => 0,
+                (Ordering::Greater, _) => 1,
+                (_, Ordering::Less) => 2,
+                _ => 3,
+            };
+            (dir, (b_r - a_r).abs() + (b_c - a_c).abs())
+        })
+        .collect()
+}
+
+fn input(rng: &mut StdRng, instructions: usize) -> String {
+    // A loop on an n×n lattice turns about 0.4 n² times.
+    let side = ((instructions as f64 / 0.4).sqrt() as usize).max(2);
+    let points = generate::random_loop(rng, side, side, 0.5);
+    // Keep only the points where the loop turns.
+    let n = points.len();
+    let corners: Vec<Pos> = (0..n)
+        .filter(|&i| {
+            let (prev, curr, next) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+            curr - prev != next - curr
+        })
+        .map(|i| points[i])
+        .collect();
+    let max_gap = 0xFFFFF / side;
+    let plan = walk(&corners, &lines(rng, side, 10), &lines(rng, side, 10));
+    let colours = walk(
+        &corners,
+        &lines(rng, side, max_gap),
+        &lines(rng, side, max_gap),
+    );
+    plan.iter()
+        .zip(&colours)
+        .map(|(&(dir, length), &(_, distance))| {
+            let dir_letter = ['R', 'D', 'L', 'U'][dir];
+            format!("{dir_letter} {length} (#{distance:05x}{dir})\n")
+        })
+        .collect()
+}
@@ -0,0 +1,99 @@
+//! Day 18: Lavaduct Lagoon
+
+pub mod generate;
+pub mod naive;
+
+use std::str::FromStr;
+
+use aoc_core::{
+    geometry::{Dir, Pos},
+    parse,
+    polygon::Polygon,
+    Answer, ParseError, PartError, Solution,
+};
+
+/// The edge that an instruction's hexadecimal colour code really describes.
+pub struct Color(pub Dir, pub isize);
+pub struct Edge(pub Dir, pub isize, pub Color);
+pub struct Input {
+    pub edges: Vec<Edge>,
+}
+
+fn parse_color(color: &str) -> Result<Color, ParseError> {
+    let hex = parse::strip_suffix(parse::strip_prefix(color, "(#")?, ")")?;
+    if hex.len() != 6 || !hex.is_char_boundary(5) {
+        return Err(ParseError::expected("six hexadecimal digits", hex));
+    }
+    let (length, dir) = hex.split_at(5);
+    let length = u32::from_str_radix(length, 16)
+        .map_err(|_| ParseError::expected("a hexadecimal length", length))?;
+    let dir = match dir {
+        "0" => Dir::Right,
+        "1" => Dir::Down,
+        "2" => Dir::Left,
+        "3" => Dir::Up,
+        _ => return Err(ParseError::expected("a direction from `0` to `3`", dir)),
+    };
+    Ok(Color(dir, length as isize))
+}
+
+fn parse_edge(line: &str) -> Result<Edge, ParseError> {
+    let (dir, rest) = parse::split_once(line, " ")?;
+    let (length, color) = parse::split_once(rest, " ")?;
+    let dir = match dir {
+        "R" => Dir::Right,
+        "L" => Dir::Left,
+        "U" => Dir::Up,
+        "D" => Dir::Down,
+        _ => return Err(ParseError::expected("`R`, `L`, `U` or `D`", dir)),
+    };
+    let length: u32 = parse::number(length)?;
+    Ok(Edge(dir, length as isize, parse_color(color)?))
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            edges: s.lines().map(parse_edge).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+pub fn part1(input: &Input) -> i128 {
+    Polygon::from_walk(
+        Pos::default(),
+        input
+            .edges
+            .iter()
+            .map(|&Edge(dir, length, _)| (dir, length)),
+    )
+    .enclosed_points()
+}
+
+pub fn part2(input: &Input) -> i128 {
+    let steps = input
+        .edges
+        .iter()
+        .map(|&Edge(_, _, Color(dir, length))| (dir, length));
+    Polygon::from_walk(Pos::default(), steps).enclosed_points()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input).into())
+    }
+}
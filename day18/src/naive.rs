@@ -0,0 +1,49 @@
+//! Straightforward solutions to check the optimised ones against.
+
+use std::collections::{HashSet, VecDeque};
+
+use aoc_core::geometry::Pos;
+
+use crate::{Edge, Input};
+
+/// Digs the trench of the plan one cube at a time, then flood-fills the
+/// ground around it and counts every cube the fill does not reach. The fill
+/// runs at twice the resolution, so that it can pass between parts of the
+/// trench that lie side by side without being joined.
+pub fn part1(input: &Input) -> usize {
+    // Cube (r, c) is at (2r, 2c), and the trench between two consecutive
+    // cubes is halfway between them.
+    let mut pos = Pos::default();
+    let mut trench = HashSet::from([pos]);
+    for &Edge(dir, length, _) in &input.edges {
+        for _ in 0..2 * length {
+            pos += dir;
+            trench.insert(pos);
+        }
+    }
+    let bounds = |coord: fn(&Pos) -> isize| {
+        let min = trench.iter().map(coord).min().unwrap();
+        let max = trench.iter().map(coord).max().unwrap();
+        (min, max)
+    };
+    let ((r_min, r_max), (c_min, c_max)) = (bounds(|p| p.r), bounds(|p| p.c));
+    let cubes = ((r_max - r_min) / 2 + 1) * ((c_max - c_min) / 2 + 1);
+    // Leave room all round for the fill to pass.
+    let (rows, cols) = (r_min - 1..=r_max + 1, c_min - 1..=c_max + 1);
+    let corner = Pos::new(*rows.start(), *cols.start());
+    let mut outside = HashSet::from([corner]);
+    let mut queue = VecDeque::from([corner]);
+    while let Some(pos) = queue.pop_front() {
+        for next in pos.neighbours4() {
+            let in_box = rows.contains(&next.r) && cols.contains(&next.c);
+            if in_box && !trench.contains(&next) && outside.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    let outside_cubes = outside
+        .iter()
+        .filter(|p| p.r % 2 == 0 && p.c % 2 == 0)
+        .count();
+    cubes as usize - outside_cubes
+}
@@ -0,0 +1,16 @@
+use day18::{generate::GENERATOR, naive, Input};
+
+/// Only part 1: the colours dig lagoons far too large to fill.
+#[test]
+fn picks_theorem_matches_flood_fill() {
+    for seed in 0..30 {
+        let instructions = 4 + 4 * seed as usize;
+        let text = GENERATOR.generate(seed, instructions);
+        let input: Input = text.parse().unwrap();
+        assert_eq!(
+            day18::part1(&input),
+            naive::part1(&input) as i128,
+            "seed {seed}:\n{text}"
+        );
+    }
+}
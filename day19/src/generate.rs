@@ -0,0 +1,65 @@
+//! Random workflows and parts.
+
+use std::collections::VecDeque;
+
+use aoc_core::generate::{self, Generator, IndexedRandom, RngExt, SliceRandom, StdRng};
+
+/// Sized by the number of workflows.
+pub const GENERATOR: Generator = Generator {
+    size: 550,
+    input,
+    answers: None,
+};
+
+/// A rule's condition on a random category, or `None` for the default.
+fn condition(rng: &mut StdRng) -> String {
+    let category = ['x', 'm', 'a', 's'].choose(rng).unwrap();
+    let cmp = if rng.random_bool(0.5) { '<' } else { '>' };
+    format!("{category}{cmp}{}", rng.random_range(2..4000))
+}
+
+/// Workflows forming a tree from `in`, so that every part ends up accepted
+/// or rejected, followed by about two parts for every five workflows.
+fn input(rng: &mut StdRng, workflows: usize) -> String {
+    let workflows = workflows.max(1);
+    let mut unused = VecDeque::from(generate::distinct_words(rng, workflows - 1, 3, &["in"]));
+    let mut lines = vec![];
+    // Workflows named by a rule but not written yet.
+    let mut pending = VecDeque::from(["in".to_owned()]);
+    while let Some(name) = pending.pop_front() {
+        let rules = rng.random_range(1..=4);
+        let mut targets: Vec<String> = (0..rules)
+            .map(|_| {
+                if !unused.is_empty() && rng.random_bool(0.6) {
+                    let next = unused.pop_front().unwrap();
+                    pending.push_back(next.clone());
+                    next
+                } else {
+                    ["A", "R"].choose(rng).unwrap().to_string()
+                }
+            })
+            .collect();
+        // Keep the tree growing until every name is used.
+        if pending.is_empty() {
+            if let Some(next) = unused.pop_front() {
+                pending.push_back(next.clone());
+                *targets.last_mut().unwrap() = next;
+            }
+        }
+        let (default, rules) = targets.split_last().unwrap();
+        let mut workflow: Vec<String> = rules
+            .iter()
+            .map(|target| format!("{}:{target}", condition(rng)))
+            .collect();
+        workflow.push(default.clone());
+        lines.push(format!("{name}{{{}}}", workflow.join(",")));
+    }
+    lines.shuffle(rng);
+    let parts: Vec<String> = (0..(2 * workflows / 5).max(1))
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|()| rng.random_range(1..=4000));
+            format!("{{x={x},m={m},a={a},s={s}}}")
+        })
+        .collect();
+    format!("{}\n\n{}\n", lines.join("\n"), parts.join("\n"))
+}
@@ -0,0 +1,310 @@
+//! Day 19: Aplenty
+
+pub mod generate;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use aoc_core::{
+    checked::{self, CheckedIterator},
+    parse, Answer, Overflow, ParseError, PartError, Solution,
+};
+
+const REJECTED: &str = "R";
+const APPROVED: &str = "A";
+const CATEGORIES: &str = "`x`, `m`, `a` or `s`";
+
+/// Splits off the first character of `s`.
+fn split_first(s: &str) -> (&str, &str) {
+    s.split_at(s.chars().next().map_or(0, char::len_utf8))
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Part {
+    pub x: i64,
+    pub m: i64,
+    pub a: i64,
+    pub s: i64,
+}
+
+impl Part {
+    pub fn rating(&self) -> Result<i64, Overflow> {
+        [self.x, self.m, self.a, self.s].into_iter().checked_sum()
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Range {
+    X(i64, i64),
+    M(i64, i64),
+    A(i64, i64),
+    S(i64, i64),
+}
+
+pub struct Condition(pub Option<Range>, pub String);
+
+pub struct Workflow {
+    pub conditions: Vec<Condition>,
+}
+
+pub struct Input {
+    pub workflows: HashMap<String, Workflow>,
+    pub parts: Vec<Part>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut line_iter = s.lines();
+        let workflows = line_iter
+            .by_ref()
+            .take_while(|line| !line.is_empty())
+            .map(|workflow_line| {
+                let (id, workflow_str) = parse::split_once(workflow_line, "{")?;
+                let workflow_str = parse::strip_suffix(workflow_str, "}")?;
+                let (rules, fallback) = match workflow_str.rsplit_once(',') {
+                    Some((rules, fallback)) => (Some(rules), fallback),
+                    None => (None, workflow_str),
+                };
+                if fallback.contains(':') {
+                    return Err(ParseError::expected("a fallback workflow", fallback));
+                }
+                let mut conditions = rules
+                    .into_iter()
+                    .flat_map(|rules| rules.split(','))
+                    .map(|condition_str| {
+                        let (condition, destination) = parse::split_once(condition_str, ":")?;
+                        let (prop, rest) = split_first(condition);
+                        let (cmp, target) = split_first(rest);
+                        let target = parse::number::<i64>(target)?;
+                        let (min, max) = match cmp {
+                            "<" => (1, target - 1),
+                            ">" => (target + 1, 4000),
+                            _ => return Err(ParseError::expected("`<` or `>`", cmp)),
+                        };
+                        let range = match prop {
+                            "x" => Range::X(min, max),
+                            "m" => Range::M(min, max),
+                            "a" => Range::A(min, max),
+                            "s" => Range::S(min, max),
+                            _ => return Err(ParseError::expected(CATEGORIES, prop)),
+                        };
+                        Ok((Some(range), destination))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                conditions.push((None, fallback));
+                Ok((id, conditions))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        if !workflows.iter().any(|&(id, _)| id == "in") {
+            return Err(ParseError::new("expected a workflow named `in`"));
+        }
+        let ids: HashSet<&str> = workflows.iter().map(|&(id, _)| id).collect();
+        if let Some(&(_, unknown)) = workflows
+            .iter()
+            .flat_map(|(_, conditions)| conditions)
+            .find(|&&(_, dest)| !ids.contains(dest) && dest != APPROVED && dest != REJECTED)
+        {
+            return Err(ParseError::expected("a workflow, `A` or `R`", unknown));
+        }
+        let workflows = workflows
+            .into_iter()
+            .map(|(id, conditions)| {
+                let conditions = conditions
+                    .into_iter()
+                    .map(|(range, dest)| Condition(range, dest.to_string()))
+                    .collect();
+                (id.to_string(), Workflow { conditions })
+            })
+            .collect();
+        let parts = line_iter
+            .map(|part_line| {
+                parse::strip_suffix(parse::strip_prefix(part_line, "{")?, "}")?
+                    .split(',')
+                    .try_fold(Part::default(), |mut part, prop| {
+                        let (key, value) = parse::split_once(prop, "=")?;
+                        let value = parse::number(value)?;
+                        match key {
+                            "x" => part.x = value,
+                            "m" => part.m = value,
+                            "a" => part.a = value,
+                            "s" => part.s = value,
+                            _ => return Err(ParseError::expected(CATEGORIES, key)),
+                        }
+                        Ok(part)
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Input { workflows, parts })
+    }
+}
+
+pub fn check(part: Part, workflows: &HashMap<String, Workflow>, id: String) -> String {
+    match workflows[&id]
+        .conditions
+        .iter()
+        .find_map(|Condition(range, dest)| {
+            if range.is_none() {
+                return Some(dest.clone());
+            }
+            if match range.unwrap() {
+                Range::X(min, max) => part.x >= min && part.x <= max,
+                Range::M(min, max) => part.m >= min && part.m <= max,
+                Range::A(min, max) => part.a >= min && part.a <= max,
+                Range::S(min, max) => part.s >= min && part.s <= max,
+            } {
+                return Some(dest.clone());
+            }
+            None
+        })
+        .expect("the fallback always applies")
+        .as_str()
+    {
+        APPROVED => APPROVED.to_string(),
+        REJECTED => REJECTED.to_string(),
+        next => check(part, workflows, next.to_string()),
+    }
+}
+
+#[derive(Debug)]
+pub struct PartRange {
+    pub x: (i64, i64),
+    pub m: (i64, i64),
+    pub a: (i64, i64),
+    pub s: (i64, i64),
+}
+
+impl PartRange {
+    pub fn rating(&self) -> Result<i64, Overflow> {
+        let x_len = self.x.1 - self.x.0 + 1;
+        let m_len = self.m.1 - self.m.0 + 1;
+        let a_len = self.a.1 - self.a.0 + 1;
+        let s_len = self.s.1 - self.s.0 + 1;
+        [x_len, m_len, a_len, s_len].into_iter().checked_product()
+    }
+
+    pub fn limit(&self, range: Option<Range>) -> PartRange {
+        if range.is_none() {
+            return PartRange { ..*self };
+        }
+        match range.unwrap() {
+            Range::X(new_min, new_max) => PartRange {
+                x: (self.x.0.max(new_min), self.x.1.min(new_max)),
+                ..*self
+            },
+            Range::M(new_min, new_max) => PartRange {
+                m: (self.m.0.max(new_min), self.m.1.min(new_max)),
+                ..*self
+            },
+            Range::A(new_min, new_max) => PartRange {
+                a: (self.a.0.max(new_min), self.a.1.min(new_max)),
+                ..*self
+            },
+            Range::S(new_min, new_max) => PartRange {
+                s: (self.s.0.max(new_min), self.s.1.min(new_max)),
+                ..*self
+            },
+        }
+    }
+}
+
+impl Default for PartRange {
+    fn default() -> Self {
+        PartRange {
+            x: (1, 4000),
+            m: (1, 4000),
+            a: (1, 4000),
+            s: (1, 4000),
+        }
+    }
+}
+
+fn is_empty((min, max): (i64, i64)) -> bool {
+    max < min
+}
+
+fn complement(part_range: &PartRange, if_range: Option<Range>) -> Option<Range> {
+    if_range.map(|if_range| {
+        let ((a, b), (a0, b0)) = match if_range {
+            Range::X(a, b) => ((a, b), part_range.x),
+            Range::M(a, b) => ((a, b), part_range.m),
+            Range::A(a, b) => ((a, b), part_range.a),
+            Range::S(a, b) => ((a, b), part_range.s),
+        };
+        let a = a.max(a0);
+        let b = b.min(b0);
+        let a1 = if a == a0 { b + 1 } else { a0 };
+        let b1 = if a == a0 { b0 } else { a - 1 };
+        match if_range {
+            Range::X(_, _) => Range::X(a1, b1),
+            Range::M(_, _) => Range::M(a1, b1),
+            Range::A(_, _) => Range::A(a1, b1),
+            Range::S(_, _) => Range::S(a1, b1),
+        }
+    })
+}
+
+/// Total rating of all combinations in `part_range` that `node` eventually approves.
+pub fn traverse(
+    graph: &HashMap<String, Workflow>,
+    node: String,
+    mut part_range: PartRange,
+) -> Result<i64, Overflow> {
+    let mut total_rating = 0;
+    for Condition(if_range, neighbor) in &graph[&node].conditions {
+        let else_range = complement(&part_range, *if_range);
+        let if_part_range = part_range.limit(*if_range);
+        part_range = part_range.limit(else_range);
+        if is_empty(if_part_range.x)
+            || is_empty(if_part_range.m)
+            || is_empty(if_part_range.a)
+            || is_empty(if_part_range.s)
+        {
+            continue;
+        }
+        if neighbor == REJECTED {
+            continue;
+        }
+        if neighbor == APPROVED {
+            total_rating = checked::add(total_rating, if_part_range.rating()?)?;
+            continue;
+        }
+        let rating = traverse(graph, neighbor.clone(), if_part_range)?;
+        total_rating = checked::add(total_rating, rating)?;
+    }
+    Ok(total_rating)
+}
+
+pub fn part1(input: &Input) -> Result<i64, Overflow> {
+    input
+        .parts
+        .iter()
+        .filter(|&part| check(*part, &input.workflows, "in".to_string()) == APPROVED)
+        .map(Part::rating)
+        .try_checked_sum()
+}
+
+pub fn part2(input: &Input) -> Result<i64, Overflow> {
+    traverse(&input.workflows, "in".to_string(), PartRange::default())
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
@@ -0,0 +1,33 @@
+//! Random game records.
+
+use aoc_core::generate::{Generator, RngExt, SliceRandom, StdRng};
+
+use crate::COLORS;
+
+/// Sized by the number of games.
+pub const GENERATOR: Generator = Generator {
+    size: 100,
+    input,
+    answers: None,
+};
+
+/// Games of up to six hands, each showing up to 20 cubes of some colors.
+fn input(rng: &mut StdRng, games: usize) -> String {
+    let mut s = String::new();
+    for id in 1..=games {
+        let hands: Vec<String> = (0..rng.random_range(1..=6))
+            .map(|_| {
+                let mut colors = COLORS.to_vec();
+                colors.shuffle(rng);
+                colors.truncate(rng.random_range(1..=3));
+                colors
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.random_range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        s.push_str(&format!("Game {id}: {}\n", hands.join("; ")));
+    }
+    s
+}
@@ -0,0 +1,120 @@
+//! Day 2: Cube Conundrum
+
+pub mod generate;
+
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_core::{
+    checked::CheckedIterator, params::Params, parse, Answer, Overflow, ParseError, PartError,
+    Solution,
+};
+use log::{debug, trace};
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+/// Cubes of each color in the bag, overridable with the `red`, `green` and
+/// `blue` parameters.
+const BAG: [(&str, i64); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+pub fn parse_game(game: &str) -> Result<(i64, HashMap<&'static str, i64>), ParseError> {
+    let (game_id, hands) = parse::split_once(game, ":")?;
+    let game_id: i64 = parse::number(parse::split_once(game_id, " ")?.1)?;
+    let hands = hands
+        .split(';')
+        .map(|hand| {
+            hand.split(',')
+                .map(|n_cubes| {
+                    let (n, color) = parse::split_once(n_cubes.trim(), " ")?;
+                    let color = COLORS
+                        .into_iter()
+                        .find(|known| *known == color)
+                        .ok_or_else(|| ParseError::expected("`red`, `green` or `blue`", color))?;
+                    Ok((color, parse::number(n)?))
+                })
+                .collect::<Result<HashMap<&str, i64>, ParseError>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let cubes = hands.into_iter().fold(
+        HashMap::from(COLORS.map(|color| (color, 0))),
+        |acc, hand| {
+            acc.into_iter()
+                .map(|(color, curr_max): (&str, i64)| {
+                    (color, curr_max.max(*hand.get(color).unwrap_or(&0)))
+                })
+                .collect()
+        },
+    );
+    Ok((game_id, cubes))
+}
+
+pub fn is_possible(observed: &HashMap<&str, i64>, limit: &HashMap<&str, i64>) -> bool {
+    trace!("observed: {observed:?}, limit: {limit:?}");
+    observed
+        .iter()
+        .all(|(color, n)| limit.get(color).map(|limit| n <= limit).unwrap_or(false))
+}
+
+pub fn part1(
+    games: &[(i64, HashMap<&str, i64>)],
+    bag: &HashMap<&str, i64>,
+) -> Result<i64, Overflow> {
+    games
+        .iter()
+        .filter(|(id, cubes)| {
+            debug!("game {id}");
+            is_possible(cubes, bag)
+        })
+        .map(|(id, _)| *id)
+        .checked_sum()
+}
+
+pub fn part2(games: &[(i64, HashMap<&str, i64>)]) -> Result<i64, Overflow> {
+    games
+        .iter()
+        .map(|(_, cubes)| cubes.values().copied().checked_product())
+        .try_checked_sum()
+}
+
+pub struct Input {
+    pub games: Vec<(i64, HashMap<&'static str, i64>)>,
+    pub bag: HashMap<&'static str, i64>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .map(parse_game)
+            .collect::<Result<_, _>>()
+            .map(|games| Input {
+                games,
+                bag: HashMap::from(BAG),
+            })
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input, ParseError> {
+        let mut input: Input = input.parse()?;
+        for (color, default) in BAG {
+            input.bag.insert(color, params.get(color, default)?);
+        }
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(&input.games, &input.bag)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(&input.games)?.into())
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_core::examples::check::<day2::Day2>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,78 @@
+//! Random module configurations.
+//!
+//! Like the real ones, a configuration is four binary counters fed by the
+//! broadcaster. Each counts presses on a chain of flip-flops until it reaches
+//! its period, when its conjunction sends a low pulse to its inverter and
+//! resets it. The inverters feed the conjunction in front of `rx`. Every
+//! module but `rx` and the broadcaster has a random name.
+
+use aoc_core::{
+    checked,
+    generate::{self, Generator, RngExt, SliceRandom, StdRng},
+    Answer,
+};
+
+/// Counters, each with the inverter that feeds `rx`'s conjunction.
+const COUNTERS: usize = 4;
+
+/// Sized by the number of flip-flops in each counter. Part 2 presses the
+/// button about `2^size` times.
+pub const GENERATOR: Generator = Generator {
+    size: 12,
+    input,
+    answers: Some(answers),
+};
+
+fn input(rng: &mut StdRng, bits: usize) -> String {
+    configuration(rng, bits).0
+}
+
+/// `rx` gets its low pulse once every counter reaches its period together.
+fn answers(rng: &mut StdRng, bits: usize) -> [Option<Answer>; 2] {
+    let periods = configuration(rng, bits).1;
+    let presses = periods
+        .into_iter()
+        .try_fold(1, checked::lcm)
+        .expect("periods are below 2^63");
+    [None, Some(presses.into())]
+}
+
+/// A configuration and the period of each of its counters.
+fn configuration(rng: &mut StdRng, bits: usize) -> (String, Vec<i64>) {
+    let mut periods = vec![];
+    let bits = bits.clamp(1, 63);
+    let mut names =
+        generate::distinct_words(rng, COUNTERS * (bits + 2) + 1, 2, &["rx"]).into_iter();
+    let last = names.next().unwrap();
+    let mut lines = vec![format!("&{last} -> rx")];
+    let mut starts = vec![];
+    for _ in 0..COUNTERS {
+        let inverter = names.next().unwrap();
+        let period: u64 = rng.random_range(1 << (bits - 1)..1 << bits) | 1;
+        periods.push(period as i64);
+        let counter = names.next().unwrap();
+        let flip_flops: Vec<String> = names.by_ref().take(bits).collect();
+        let mut resets = vec![flip_flops[0].clone()];
+        for (j, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs = vec![];
+            if let Some(next) = flip_flops.get(j + 1) {
+                outputs.push(next.clone());
+            }
+            if period >> j & 1 == 1 {
+                outputs.push(counter.clone());
+            } else {
+                resets.push(flip_flop.clone());
+            }
+            outputs.shuffle(rng);
+            lines.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+        }
+        resets.push(inverter.clone());
+        resets.shuffle(rng);
+        lines.push(format!("&{counter} -> {}", resets.join(", ")));
+        lines.push(format!("&{inverter} -> {last}"));
+        starts.push(flip_flops[0].clone());
+    }
+    lines.push(format!("broadcaster -> {}", starts.join(", ")));
+    lines.shuffle(rng);
+    (lines.join("\n") + "\n", periods)
+}
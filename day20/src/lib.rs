@@ -0,0 +1,363 @@
+//! Day 20: Pulse Propagation
+
+pub mod generate;
+pub mod naive;
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    ops::AddAssign,
+    str::FromStr,
+};
+
+use aoc_core::{checked, params::Params, parse, Answer, Overflow, ParseError, PartError, Solution};
+use log::debug;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+fn flip(pulse: Pulse) -> Pulse {
+    match pulse {
+        Pulse::Low => Pulse::High,
+        Pulse::High => Pulse::Low,
+    }
+}
+
+pub type GateId = String;
+
+#[derive(Debug)]
+pub struct Signal {
+    pub sender: GateId,
+    pub receiver: GateId,
+    pub pulse: Pulse,
+}
+
+#[derive(Debug, Clone)]
+pub enum GateState {
+    Broadcast,
+    FlipFlop {
+        state: Pulse, // Low == off, High == on
+    },
+    Conjunction {
+        src_states: HashMap<GateId, Pulse>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct Gate {
+    pub state: GateState,
+    pub outs: Vec<GateId>,
+}
+
+impl Gate {
+    pub fn receive(&mut self, signal: Signal) -> Vec<Signal> {
+        let out_pulse = match &self.state {
+            GateState::Broadcast => {
+                assert!(signal.pulse == Pulse::Low);
+                Some(Pulse::Low)
+            }
+            GateState::FlipFlop { state } => {
+                if signal.pulse == Pulse::Low {
+                    let new_state = flip(*state);
+                    self.state = GateState::FlipFlop { state: new_state };
+                    Some(new_state)
+                } else {
+                    None
+                }
+            }
+            GateState::Conjunction { src_states } => {
+                let mut new_src_states = src_states.clone();
+                *new_src_states.get_mut(&signal.sender).unwrap() = signal.pulse;
+                let out_pulse = if new_src_states.values().all(|&p| p == Pulse::High) {
+                    Pulse::Low
+                } else {
+                    Pulse::High
+                };
+                self.state = GateState::Conjunction {
+                    src_states: new_src_states,
+                };
+                Some(out_pulse)
+            }
+        };
+        match out_pulse {
+            Some(out_pulse) => self
+                .outs
+                .iter()
+                .map(|out| Signal {
+                    sender: signal.receiver.clone(),
+                    receiver: out.clone(),
+                    pulse: out_pulse,
+                })
+                .collect(),
+            None => vec![],
+        }
+    }
+}
+
+/// Button presses counted in part 1.
+const PRESSES: usize = 1000;
+
+#[derive(Debug)]
+pub struct Input {
+    pub gates: HashMap<GateId, Gate>,
+    pub presses: usize,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut gates = s
+            .lines()
+            .map(|line| {
+                let (gate_id, outs) = parse::split_once(line, " -> ")?;
+                let outs = outs.split(", ").map(String::from).collect();
+                if let Some(gate_id) = gate_id.strip_prefix('%') {
+                    Ok((
+                        gate_id.into(),
+                        Gate {
+                            state: GateState::FlipFlop { state: Pulse::Low },
+                            outs,
+                        },
+                    ))
+                } else if let Some(gate_id) = gate_id.strip_prefix('&') {
+                    Ok((
+                        gate_id.into(),
+                        Gate {
+                            state: GateState::Conjunction {
+                                src_states: HashMap::new(),
+                            },
+                            outs,
+                        },
+                    ))
+                } else if gate_id == "broadcaster" {
+                    Ok((
+                        gate_id.into(),
+                        Gate {
+                            state: GateState::Broadcast,
+                            outs,
+                        },
+                    ))
+                } else {
+                    Err(ParseError::expected(
+                        "`%name`, `&name` or `broadcaster`",
+                        gate_id,
+                    ))
+                }
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?
+            .into_iter()
+            .fold(HashMap::<GateId, Gate>::new(), |mut gates, (id, gate)| {
+                gates.insert(id, gate);
+                gates
+            });
+        let mut sources = HashMap::new();
+        for (src, gate) in gates.iter() {
+            for out in gate.outs.iter() {
+                let gate = gates.get(out);
+                if gate.is_none() {
+                    continue;
+                }
+                if let GateState::Conjunction { .. } = gate.unwrap().state {
+                    sources
+                        .entry(out.clone())
+                        .and_modify(|srcs: &mut Vec<GateId>| srcs.push(src.clone()))
+                        .or_insert_with(|| vec![src.clone()]);
+                }
+            }
+        }
+        for (conj, srcs) in sources.into_iter() {
+            gates.entry(conj).and_modify(|gate: &mut Gate| {
+                gate.state = GateState::Conjunction {
+                    src_states: srcs.into_iter().map(|src| (src, Pulse::Low)).collect(),
+                }
+            });
+        }
+        Ok(Input {
+            gates,
+            presses: PRESSES,
+        })
+    }
+}
+
+/// Pushes the button once, folding every signal sent through `signal_reducer`.
+pub fn broadcast_low<T>(
+    mut gates: HashMap<GateId, Gate>,
+    signal_reducer: impl Fn(&Signal) -> T,
+) -> (HashMap<GateId, Gate>, T)
+where
+    T: Default + AddAssign,
+{
+    let mut signals = VecDeque::new();
+    signals.push_back(Signal {
+        sender: GateId::from("button"),
+        receiver: GateId::from("broadcaster"),
+        pulse: Pulse::Low,
+    });
+    let mut acc = T::default();
+    while let Some(signal) = signals.pop_front() {
+        acc += signal_reducer(&signal);
+        if let Some(gate) = gates.get_mut(&signal.receiver) {
+            gate.receive(signal)
+                .into_iter()
+                .for_each(|signal| signals.push_back(signal));
+        }
+    }
+    (gates, acc)
+}
+
+#[derive(Default)]
+struct LowHighPulseCount {
+    lows: i64,
+    highs: i64,
+}
+
+impl LowHighPulseCount {
+    fn checked_add(self, rhs: Self) -> Result<Self, Overflow> {
+        Ok(LowHighPulseCount {
+            lows: checked::add(self.lows, rhs.lows)?,
+            highs: checked::add(self.highs, rhs.highs)?,
+        })
+    }
+}
+
+impl AddAssign for LowHighPulseCount {
+    fn add_assign(&mut self, rhs: Self) {
+        self.lows += rhs.lows;
+        self.highs += rhs.highs;
+    }
+}
+
+fn count_low_high_pulses(signal: &Signal) -> LowHighPulseCount {
+    match signal.pulse {
+        Pulse::Low => LowHighPulseCount { lows: 1, highs: 0 },
+        Pulse::High => LowHighPulseCount { lows: 0, highs: 1 },
+    }
+}
+
+pub fn part1(input: &Input) -> Result<i64, Overflow> {
+    let (_, LowHighPulseCount { lows, highs }) = (0..input.presses).try_fold(
+        (input.gates.clone(), LowHighPulseCount::default()),
+        |(gates, lows_highs), _| {
+            let (new_gates, new_lows_highs) = broadcast_low(gates, count_low_high_pulses);
+            Ok::<_, Overflow>((new_gates, lows_highs.checked_add(new_lows_highs)?))
+        },
+    )?;
+    checked::mul(lows, highs)
+}
+
+fn count_lows_to(dest: &str, signal: &Signal) -> i64 {
+    if signal.receiver == dest && signal.pulse == Pulse::Low {
+        1
+    } else {
+        0
+    }
+}
+
+/// Every flip-flop's state and every conjunction's memory, in a fixed order,
+/// to tell when the modules are back in a state they were in before.
+fn snapshot(gates: &HashMap<GateId, Gate>) -> Vec<bool> {
+    let mut ids: Vec<&GateId> = gates.keys().collect();
+    ids.sort();
+    let mut bits = vec![];
+    for id in ids {
+        match &gates[id].state {
+            GateState::Broadcast => {}
+            GateState::FlipFlop { state } => bits.push(*state == Pulse::High),
+            GateState::Conjunction { src_states } => {
+                let mut srcs: Vec<_> = src_states.iter().collect();
+                srcs.sort_by_key(|&(src, _)| src);
+                bits.extend(srcs.into_iter().map(|(_, &pulse)| pulse == Pulse::High));
+            }
+        }
+    }
+    bits
+}
+
+/// Presses until `feeder` first sends a high pulse to `conj`, or `None` if
+/// the modules return to an earlier state without it ever doing so.
+fn first_high(input: &Input, feeder: &str, conj: &str) -> Option<i64> {
+    let mut gates = input.gates.clone();
+    let mut seen = HashSet::from([snapshot(&gates)]);
+    let mut presses = 0;
+    loop {
+        presses += 1;
+        let (new_gates, highs) = broadcast_low(gates, |signal| {
+            i64::from(
+                signal.sender == feeder && signal.receiver == conj && signal.pulse == Pulse::High,
+            )
+        });
+        if highs > 0 {
+            return Some(presses);
+        }
+        if !seen.insert(snapshot(&new_gates)) {
+            return None;
+        }
+        gates = new_gates;
+    }
+}
+
+/// `rx` is fed by a single conjunction, which sends it a low pulse once all
+/// of its inputs last sent a high one. Each input does so on a cycle of its
+/// own that starts from the first press, so the answer is the least common
+/// multiple of the presses until each first does.
+pub fn part2(input: &Input) -> Result<i64, PartError> {
+    let senders: Vec<(&GateId, &Gate)> = input
+        .gates
+        .iter()
+        .filter(|(_, gate)| gate.outs.iter().any(|out| out == "rx"))
+        .collect();
+    let (conj, feeders) = match senders[..] {
+        [] => return Err(PartError::no_answer("no module sends to `rx`")),
+        [(
+            conj,
+            Gate {
+                state: GateState::Conjunction { src_states },
+                ..
+            },
+        )] => (conj, src_states),
+        _ => {
+            return Err(PartError::no_answer(
+                "`rx` must be fed by a single conjunction",
+            ))
+        }
+    };
+    if feeders.is_empty() {
+        return Err(PartError::no_answer(format!("no module feeds `{conj}`")));
+    }
+    let mut feeders: Vec<&GateId> = feeders.keys().collect();
+    feeders.sort();
+    feeders.into_iter().try_fold(1, |lcm, feeder| {
+        let presses = first_high(input, feeder, conj).ok_or_else(|| {
+            PartError::no_answer(format!("`{feeder}` never sends `{conj}` a high pulse"))
+        })?;
+        debug!("{feeder} first sends {conj} a high pulse on press {presses}");
+        Ok(checked::lcm(lcm, presses)?)
+    })
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input, ParseError> {
+        let mut input: Input = input.parse()?;
+        input.presses = params.get("presses", PRESSES)?;
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
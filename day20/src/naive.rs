@@ -0,0 +1,17 @@
+//! Straightforward solutions to check the optimised ones against.
+
+use crate::{broadcast_low, count_lows_to, Input};
+
+/// Presses the button until `rx` receives a low pulse.
+pub fn part2(input: &Input) -> i64 {
+    let mut gates = input.gates.clone();
+    let mut presses = 0;
+    loop {
+        presses += 1;
+        let (new_gates, lows) = broadcast_low(gates, |signal| count_lows_to("rx", signal));
+        if lows > 0 {
+            return presses;
+        }
+        gates = new_gates;
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_core::examples::check::<day20::Day20>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,16 @@
+use day20::{generate::GENERATOR, naive, Input};
+
+#[test]
+fn lcm_matches_pressing_until_rx_receives_a_low_pulse() {
+    for seed in 0..5 {
+        // Counters of four flip-flops, whose periods multiply to at most
+        // 9 × 11 × 13 × 15 presses.
+        let text = GENERATOR.generate(seed, 4);
+        let input: Input = text.parse().unwrap();
+        assert_eq!(
+            day20::part2(&input),
+            Ok(naive::part2(&input)),
+            "seed {seed}:\n{text}"
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_core::PartError;
+use day20::Input;
+
+fn no_answer(text: &str) -> String {
+    let input: Input = text.parse().unwrap();
+    match day20::part2(&input) {
+        Err(PartError::NoAnswer(reason)) => reason,
+        answer => panic!("{text:?} answered {answer:?}"),
+    }
+}
+
+#[test]
+fn rx_must_be_there() {
+    assert_eq!(
+        no_answer("broadcaster -> a\n%a -> \n"),
+        "no module sends to `rx`"
+    );
+}
+
+#[test]
+fn rx_must_have_one_conjunction_in_front() {
+    assert!(no_answer("broadcaster -> a\n%a -> rx\n").contains("single conjunction"));
+    assert!(no_answer("broadcaster -> a, b\n&a -> rx\n&b -> rx\n").contains("single conjunction"));
+    assert_eq!(
+        no_answer("broadcaster -> a\n&c -> rx\n%a -> \n"),
+        "no module feeds `c`"
+    );
+}
+
+#[test]
+fn stops_when_a_feeder_never_fires() {
+    assert_eq!(
+        no_answer("broadcaster -> a\n%a -> c\n&b -> c\n&c -> rx\n"),
+        "`b` never sends `c` a high pulse"
+    );
+}
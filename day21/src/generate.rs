@@ -0,0 +1,33 @@
+//! Random gardens.
+
+use aoc_core::generate::{self, Generator, RngExt, StdRng};
+
+/// Sized by the side of the garden. Unless the side is 131 like the real
+/// one, part 2 needs `far_steps` set to half the side plus a multiple of it.
+pub const GENERATOR: Generator = Generator {
+    size: 131,
+    input,
+    answers: None,
+};
+
+/// A square garden with an odd side and the start in its middle. Like the
+/// real ones, its middle row and column and its edges are free of rocks.
+fn input(rng: &mut StdRng, side: usize) -> String {
+    let side = side.max(1) | 1;
+    let middle = (side / 2) as isize;
+    let last = side as isize - 1;
+    generate::grid(side, side, |pos| {
+        if pos.r == middle && pos.c == middle {
+            'S'
+        } else if [pos.r, pos.c]
+            .iter()
+            .any(|&x| x == 0 || x == middle || x == last)
+        {
+            '.'
+        } else if rng.random_bool(0.1) {
+            '#'
+        } else {
+            '.'
+        }
+    })
+}
@@ -0,0 +1,129 @@
+//! Day 21: Step Counter
+
+pub mod generate;
+pub mod naive;
+
+use std::{str::FromStr, vec};
+
+use aoc_core::{
+    checked,
+    geometry::Pos,
+    graph::{self, FnGraph},
+    grid::Grid,
+    params::Params,
+    Answer, ParseError, PartError, Solution,
+};
+use log::debug;
+
+/// The garden map, repeated infinitely in every direction.
+pub type Mtx = Grid<char>;
+
+/// Steps taken in part 1.
+const STEPS: usize = 64;
+/// Steps taken in part 2, on the infinite map.
+const FAR_STEPS: usize = 26501365;
+
+pub struct Input {
+    pub mtx: Mtx,
+    pub steps: usize,
+    pub far_steps: usize,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Input {
+            mtx: s.parse()?,
+            steps: STEPS,
+            far_steps: FAR_STEPS,
+        })
+    }
+}
+
+pub fn find_start(mtx: &Mtx) -> Result<Pos, PartError> {
+    mtx.position(|&ch| ch == 'S')
+        .ok_or_else(|| PartError::no_answer("there is no starting plot `S`"))
+}
+
+/// Plots reachable in exactly `steps` steps. The garden is a grid, so those
+/// are the plots within `steps` whose distance has the same parity.
+pub fn count_reachable(mtx: &Mtx, start: Pos, steps: usize) -> i64 {
+    let garden = FnGraph(|&p: &Pos| {
+        p.neighbours4()
+            .filter(|&next| *mtx.get_wrapping(next) != '#')
+            .map(|next| (next, 1))
+    });
+    graph::bfs_within(&garden, start, steps)
+        .into_values()
+        .filter(|depth| depth % 2 == steps % 2)
+        .count() as i64
+}
+
+pub fn part1(mtx: &Mtx, steps: usize) -> Result<i64, PartError> {
+    Ok(count_reachable(mtx, find_start(mtx)?, steps))
+}
+
+/// Plots reachable in exactly `steps` steps on the infinite map, where
+/// `steps` takes the start to the edge of its copy of the garden and then
+/// across a whole number of copies. The start is in the middle and its row
+/// and column are clear, so the reachable area grows by whole copies and
+/// its size is a quadratic function of the number crossed.
+pub fn part2(mtx: &Mtx, steps: usize) -> Result<i64, PartError> {
+    let side = mtx.width();
+    let half = side / 2;
+    if steps < half || !(steps - half).is_multiple_of(side) {
+        return Err(PartError::no_answer(format!(
+            "`far_steps` must be {half} + {side}k for a map {side} wide, got {steps}"
+        )));
+    }
+    let k = (steps - half) / side; // 202300 on the real input
+    let start = find_start(mtx)?;
+    let mut f = vec![
+        count_reachable(mtx, start, half),
+        count_reachable(mtx, start, half + side),
+        count_reachable(mtx, start, half + 2 * side),
+    ];
+    debug!(
+        "reachable after {half}, {} and {} steps: {f:?}",
+        half + side,
+        half + 2 * side
+    );
+    if k < f.len() {
+        return Ok(f[k]);
+    }
+    // assume f(x) is a quadratic function
+    let double_derivative = (f[2] - f[1]) - (f[1] - f[0]);
+    for _ in 3..=k {
+        let f3 = checked::add(f[2], checked::add(f[2] - f[1], double_derivative)?)?;
+        f[0] = f[1];
+        f[1] = f[2];
+        f[2] = f3;
+    }
+    Ok(f[2])
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input, ParseError> {
+        let mut input: Input = input.parse()?;
+        input.steps = params.get("steps", STEPS)?;
+        input.far_steps = params.get("far_steps", FAR_STEPS)?;
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(&input.mtx, input.steps)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(&input.mtx, input.far_steps)?.into())
+    }
+}
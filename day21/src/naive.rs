@@ -0,0 +1,21 @@
+//! Straightforward solutions to check the optimised ones against.
+
+use std::collections::HashSet;
+
+use aoc_core::geometry::Pos;
+
+use crate::Mtx;
+
+/// Plots reachable from `start` in exactly `steps` steps on the infinite
+/// map, taking every step from every plot reached so far.
+pub fn count_reachable(mtx: &Mtx, start: Pos, steps: usize) -> i64 {
+    let mut reached = HashSet::from([start]);
+    for _ in 0..steps {
+        reached = reached
+            .iter()
+            .flat_map(|pos| pos.neighbours4())
+            .filter(|&next| *mtx.get_wrapping(next) != '#')
+            .collect();
+    }
+    reached.len() as i64
+}
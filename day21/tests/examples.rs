@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_core::examples::check::<day21::Day21>(env!("CARGO_MANIFEST_DIR"));
+}
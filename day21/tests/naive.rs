@@ -0,0 +1,19 @@
+use day21::{generate::GENERATOR, naive, Input};
+
+#[test]
+fn extrapolation_matches_taking_every_step() {
+    for seed in 0..10 {
+        let side = 7 + 2 * (seed as usize % 4);
+        let text = GENERATOR.generate(seed, side);
+        let Input { mtx, .. } = text.parse().unwrap();
+        let start = day21::find_start(&mtx).unwrap();
+        for k in 0..5 {
+            let steps = side / 2 + k * side;
+            assert_eq!(
+                day21::part2(&mtx, steps),
+                Ok(naive::count_reachable(&mtx, start, steps)),
+                "seed {seed}, {steps} steps:\n{text}"
+            );
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_core::PartError;
+use day21::Input;
+
+#[test]
+fn the_elf_needs_a_start() {
+    let Input { mtx, .. } = "...\n.#.\n...\n".parse().unwrap();
+    let no_start = PartError::no_answer("there is no starting plot `S`");
+    assert_eq!(day21::part1(&mtx, 6), Err(no_start.clone()));
+    assert_eq!(day21::part2(&mtx, 4), Err(no_start));
+}
@@ -0,0 +1,52 @@
+//! Random snapshots of falling bricks.
+
+use std::collections::HashSet;
+
+use aoc_core::generate::{Generator, RngExt, StdRng};
+
+/// Sized by the number of bricks.
+pub const GENERATOR: Generator = Generator {
+    size: 1260,
+    input,
+    answers: None,
+};
+
+/// Bricks of up to five cubes in a line, over a 10×10 area that may start
+/// away from x = 0 and y = 0, none of them overlapping.
+fn input(rng: &mut StdRng, bricks: usize) -> String {
+    let (x0, y0) = (rng.random_range(0..=3), rng.random_range(0..=3));
+    let height = bricks / 3 + 10;
+    let mut taken = HashSet::new();
+    let mut s = String::new();
+    let mut placed = 0;
+    while placed < bricks {
+        let len = rng.random_range(0..5);
+        let axis = rng.random_range(0..3);
+        let start = [
+            x0 + rng.random_range(0..10),
+            y0 + rng.random_range(0..10),
+            rng.random_range(1..=height),
+        ];
+        let mut end = start;
+        end[axis] += len;
+        if axis < 2 && end[axis] >= [x0, y0][axis] + 10 {
+            continue;
+        }
+        let cubes: Vec<[usize; 3]> = (0..=len)
+            .map(|i| {
+                let mut cube = start;
+                cube[axis] += i;
+                cube
+            })
+            .collect();
+        if cubes.iter().any(|cube| taken.contains(cube)) {
+            continue;
+        }
+        taken.extend(cubes);
+        let [x, y, z] = start;
+        let [x1, y1, z1] = end;
+        s.push_str(&format!("{x},{y},{z}~{x1},{y1},{z1}\n"));
+        placed += 1;
+    }
+    s
+}
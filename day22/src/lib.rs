@@ -0,0 +1,207 @@
+//! Day 22: Sand Slabs
+
+pub mod generate;
+
+use std::{
+    collections::{HashSet, VecDeque},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use aoc_core::{
+    checked::CheckedIterator, geometry::Point3, parse, Answer, Overflow, ParseError, PartError,
+    Solution,
+};
+
+pub type Point = Point3<i64>;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Cuboid {
+    pub p0: Point,
+    pub p1: Point,
+}
+
+pub struct Input {
+    pub cuboids: Vec<Cuboid>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cuboids: Vec<Cuboid> = s
+            .lines()
+            .map(|line| {
+                let (start, end) = parse::split_once(line, "~")?;
+                let p0 = start.parse::<Point>()?;
+                let p1 = end.parse::<Point>()?;
+                if p1.x < p0.x || p1.y < p0.y || p1.z < p0.z {
+                    return Err(ParseError::expected(
+                        format!("an end no lower than `{start}` on any axis"),
+                        end,
+                    ));
+                }
+                Ok(Cuboid {
+                    p0,
+                    p1: p1 + Point { x: 1, y: 1, z: 1 },
+                })
+            })
+            .collect::<Result<_, ParseError>>()?;
+        if cuboids.is_empty() {
+            return Err(ParseError::expected("a brick", &s[s.len()..]));
+        }
+        Ok(Input { cuboids })
+    }
+}
+
+#[derive(Debug)]
+pub struct Stacked {
+    pub cuboid: Cuboid,
+    pub cuboids_under: Vec<isize>,
+    pub cuboids_over: Vec<isize>,
+}
+
+/// The smallest x and y of the cuboids, and how far they spread from there.
+fn xy_spread(cuboids: &[Cuboid]) -> (i64, i64, usize, usize) {
+    let (x_min, y_min, x_max, y_max) = cuboids
+        .iter()
+        .map(|c| (c.p0.x, c.p0.y, c.p1.x, c.p1.y))
+        .fold(
+            (i64::MAX, i64::MAX, i64::MIN, i64::MIN),
+            |(x_min, y_min, x_max, y_max), (x0, y0, x1, y1)| {
+                (x_min.min(x0), y_min.min(y0), x_max.max(x1), y_max.max(y1))
+            },
+        );
+    (
+        x_min,
+        y_min,
+        (x_max - x_min) as usize,
+        (y_max - y_min) as usize,
+    )
+}
+
+pub fn stack(mut cuboids: Vec<Cuboid>) -> Vec<Stacked> {
+    cuboids.sort_by_key(|cuboid| cuboid.p0.z);
+    let (x_min, y_min, dx, dy) = xy_spread(&cuboids);
+    let mut mask: Vec<Vec<isize>> = vec![vec![-1; dy]; dx];
+    let cell = |x: i64, y: i64| ((x - x_min) as usize, (y - y_min) as usize);
+    let mut stacked_cuboids: Vec<Stacked> = Vec::new();
+    for cuboid in cuboids {
+        let curr_idx = stacked_cuboids.len() as isize;
+        let cuboids_under = (cuboid.p0.x..cuboid.p1.x)
+            .cartesian_product(cuboid.p0.y..cuboid.p1.y)
+            .map(|(x, y)| {
+                let (x, y) = cell(x, y);
+                mask[x][y]
+            })
+            .unique()
+            .map(|idx| {
+                if idx == -1 {
+                    (idx, 0)
+                } else {
+                    (idx, stacked_cuboids[idx as usize].cuboid.p1.z)
+                }
+            })
+            .collect::<Vec<_>>();
+        let base_z = *cuboids_under.iter().map(|(_, z)| z).max().unwrap();
+        let dz = Point {
+            x: 0,
+            y: 0,
+            z: base_z - cuboid.p0.z,
+        };
+        let cuboid = Cuboid {
+            p0: cuboid.p0 + dz,
+            p1: cuboid.p1 + dz,
+        };
+        let cuboids_under = cuboids_under
+            .into_iter()
+            .filter_map(|(idx, z)| {
+                if z == base_z && idx != -1 {
+                    Some(idx)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<isize>>();
+        for &c_under in cuboids_under.iter() {
+            stacked_cuboids[c_under as usize]
+                .cuboids_over
+                .push(curr_idx);
+        }
+        stacked_cuboids.push(Stacked {
+            cuboid,
+            cuboids_under,
+            cuboids_over: Vec::new(),
+        });
+        for x in cuboid.p0.x..cuboid.p1.x {
+            for y in cuboid.p0.y..cuboid.p1.y {
+                let (x, y) = cell(x, y);
+                mask[x][y] = curr_idx;
+            }
+        }
+    }
+    stacked_cuboids
+}
+
+pub fn part1(cuboids: &[Cuboid]) -> usize {
+    let stacked_cuboids = stack(cuboids.to_vec());
+    stacked_cuboids
+        .iter()
+        .filter(|&Stacked { cuboids_over, .. }| {
+            cuboids_over
+                .iter()
+                .all(|&cuboid_over| stacked_cuboids[cuboid_over as usize].cuboids_under.len() > 1)
+        })
+        .count()
+}
+
+pub fn remove(cuboid_idx: isize, stacked: &[Stacked]) -> HashSet<isize> {
+    let mut removed = HashSet::new();
+    removed.insert(cuboid_idx);
+    let mut queue = VecDeque::new();
+    for &cuboid_over in stacked[cuboid_idx as usize].cuboids_over.iter() {
+        queue.push_back(cuboid_over);
+    }
+    while let Some(cuboid_idx) = queue.pop_front() {
+        let cuboid = &stacked[cuboid_idx as usize];
+        if cuboid
+            .cuboids_under
+            .iter()
+            .filter(|under| !removed.contains(under))
+            .count()
+            == 0
+        {
+            removed.insert(cuboid_idx);
+        }
+        for &over in cuboid.cuboids_over.iter() {
+            queue.push_back(over);
+        }
+    }
+    removed
+}
+
+pub fn part2(cuboids: &[Cuboid]) -> Result<usize, Overflow> {
+    let stacked_cuboids = stack(cuboids.to_vec());
+    (0..stacked_cuboids.len())
+        .map(|cuboid_idx| remove(cuboid_idx as isize, &stacked_cuboids).len() - 1)
+        .checked_sum()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(&input.cuboids).into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(&input.cuboids)?.into())
+    }
+}
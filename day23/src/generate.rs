@@ -0,0 +1,109 @@
+//! Random hiking trail maps.
+//!
+//! Like the real maps, the trails form a lattice of junctions, each with a
+//! slope on every trail leading away from it to the right or down, so that
+//! paths that respect the slopes never go round in circles. Trails bulge out
+//! by random amounts to give them different lengths.
+
+use std::collections::HashMap;
+
+use aoc_core::{
+    generate::{self, Generator, RngExt, StdRng},
+    geometry::Pos,
+};
+
+/// Sized by the side of the map.
+pub const GENERATOR: Generator = Generator {
+    size: 141,
+    input,
+    answers: None,
+};
+
+/// Lays a trail from `from` to `to` in a straight line, after the ones
+/// already in `trails`.
+fn straight(trails: &mut HashMap<Pos, char>, from: Pos, to: Pos) {
+    let step = Pos::new((to.r - from.r).signum(), (to.c - from.c).signum());
+    let mut pos = from;
+    trails.entry(pos).or_insert('.');
+    while pos != to {
+        pos += step;
+        trails.entry(pos).or_insert('.');
+    }
+}
+
+/// Lays a trail from the junction `from` to the junction `to` in the same
+/// row or column, bulging sideways by `bulge` between `inset` tiles from
+/// either end.
+fn trail(trails: &mut HashMap<Pos, char>, from: Pos, to: Pos, inset: isize, bulge: isize) {
+    let along = Pos::new((to.r - from.r).signum(), (to.c - from.c).signum());
+    let aside = Pos::new(along.c, along.r);
+    let (a, d) = (from + along * inset, to - along * inset);
+    for (p, q) in [
+        (from, a),
+        (a, a + aside * bulge),
+        (a + aside * bulge, d + aside * bulge),
+        (d + aside * bulge, d),
+        (d, to),
+    ] {
+        straight(trails, p, q);
+    }
+}
+
+/// Where each of `count` junctions goes along a side, from `first` to `last`.
+fn spread(count: usize, first: isize, last: isize) -> Vec<isize> {
+    (0..count as isize)
+        .map(|i| first + i * (last - first) / (count as isize - 1))
+        .collect()
+}
+
+fn input(rng: &mut StdRng, side: usize) -> String {
+    let side = side.max(11);
+    let n = side as isize;
+    let count = ((side - 3) / 20 + 1).clamp(2, 6);
+    // Rows leave room for the trails from the start and to the end.
+    let rows = spread(count, 3, n - 4);
+    let cols = spread(count, 1, n - 2);
+    let spacing = (n - 7) / (count as isize - 1);
+    let max_bulge = ((spacing - 6) / 2).max(0);
+    let inset = max_bulge + 2;
+    let mut trails = HashMap::new();
+    let pick_bulge = |rng: &mut StdRng, first: bool, last: bool| {
+        let bulge = rng.random_range(0..=max_bulge as usize) as isize;
+        match (first, last) {
+            (true, _) => bulge,
+            (_, true) => -bulge,
+            _ if rng.random_bool(0.5) => bulge,
+            _ => -bulge,
+        }
+    };
+    for (i, &r) in rows.iter().enumerate() {
+        for (j, &c) in cols.iter().enumerate() {
+            let junction = Pos::new(r, c);
+            if let Some(&right) = cols.get(j + 1) {
+                // Row trails bulge up or down, away from the map's edges.
+                let bulge = pick_bulge(rng, i == 0, i == count - 1);
+                trail(&mut trails, junction, Pos::new(r, right), inset, bulge);
+            }
+            if let Some(&down) = rows.get(i + 1) {
+                let bulge = pick_bulge(rng, j == 0, j == count - 1);
+                trail(&mut trails, junction, Pos::new(down, c), inset, bulge);
+            }
+        }
+    }
+    straight(&mut trails, Pos::new(0, 1), Pos::new(rows[0], 1));
+    straight(
+        &mut trails,
+        Pos::new(rows[count - 1], n - 2),
+        Pos::new(n - 1, n - 2),
+    );
+    for &r in &rows {
+        for &c in &cols {
+            for (dr, dc, slope) in [(0, -1, '>'), (0, 1, '>'), (-1, 0, 'v'), (1, 0, 'v')] {
+                if let Some(tile) = trails.get_mut(&Pos::new(r + dr, c + dc)) {
+                    *tile = slope;
+                }
+            }
+        }
+    }
+    generate::grid(side, side, |pos| trails.get(&pos).copied().unwrap_or('#'))
+}
@@ -0,0 +1,167 @@
+//! Day 23: A Long Walk
+
+pub mod generate;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    vec,
+};
+
+use aoc_core::{
+    geometry::{Dir, Pos},
+    graph::{self, Graph},
+    grid::Grid,
+    Answer, ParseError, PartError, Solution,
+};
+
+pub type Matrix = Grid<char>;
+
+pub struct Input {
+    pub mtx: Matrix,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Input {
+            mtx: Grid::of_tiles(s, "#.<>^v")?,
+        })
+    }
+}
+
+pub fn part1(mtx: &Matrix) -> usize {
+    let rows = mtx.height() as isize;
+    let cols = mtx.width() as isize;
+    let dest_pos: Pos = Pos {
+        r: rows,
+        c: cols - 2,
+    };
+    let mut max_path = 0;
+    let mut num_steps = 0;
+    let mut paths = vec![(Pos { r: 0, c: 1 }, Dir::Down)];
+    while !paths.is_empty() {
+        paths = paths
+            .into_iter()
+            .flat_map(|(pos, dir)| {
+                let field = mtx[pos];
+                let directions = match field {
+                    '.' => vec![dir, dir.turn_left(), dir.turn_right()],
+                    '>' => {
+                        if dir == Dir::Left {
+                            vec![]
+                        } else {
+                            vec![Dir::Right]
+                        }
+                    }
+                    '<' => {
+                        if dir == Dir::Right {
+                            vec![]
+                        } else {
+                            vec![Dir::Left]
+                        }
+                    }
+                    '^' => {
+                        if dir == Dir::Down {
+                            vec![]
+                        } else {
+                            vec![Dir::Up]
+                        }
+                    }
+                    'v' => {
+                        if dir == Dir::Up {
+                            vec![]
+                        } else {
+                            vec![Dir::Down]
+                        }
+                    }
+                    _ => unreachable!("walks stay out of the forest, the only other tile"),
+                };
+                directions
+                    .into_iter()
+                    .map(|dir| (pos + dir, dir))
+                    .filter_map(|(next_pos, dir)| {
+                        if next_pos == dest_pos {
+                            max_path = num_steps;
+                            return None;
+                        }
+                        if mtx[next_pos] == '#' {
+                            return None;
+                        }
+                        Some((next_pos, dir))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        num_steps += 1;
+    }
+    max_path
+}
+
+fn is_path(ch: char) -> bool {
+    ch != '#'
+}
+
+/// The maze compressed to its junctions, with corridor lengths as weights.
+pub type Junctions = HashMap<Pos, Vec<(Pos, usize)>>;
+
+pub fn build_graph(mtx: &Matrix) -> Junctions {
+    graph::contract_corridors(mtx, |&ch| is_path(ch))
+}
+
+pub fn longest_path(
+    graph: &impl Graph<Pos, usize>,
+    from: Pos,
+    to: Pos,
+    mut visited: HashSet<Pos>,
+    distance_so_far: usize,
+) -> (HashSet<Pos>, usize) {
+    if from == to {
+        return (visited, distance_so_far);
+    }
+    let mut longest_distance = 0;
+    visited.insert(from);
+    for (neighbor, distance) in graph.edges(&from) {
+        if visited.contains(&neighbor) {
+            continue;
+        }
+        let (new_visited, new_distance) =
+            longest_path(graph, neighbor, to, visited, distance_so_far + distance);
+        visited = new_visited;
+        longest_distance = longest_distance.max(new_distance);
+    }
+    visited.remove(&from);
+    (visited, longest_distance)
+}
+
+pub fn part2(mtx: &Matrix) -> usize {
+    let rows = mtx.height() as isize;
+    let cols = mtx.width() as isize;
+    let start_pos = Pos { r: 0, c: 1 };
+    let dest_pos = Pos {
+        r: rows - 1,
+        c: cols - 2,
+    };
+    let graph = build_graph(mtx);
+    let (_, distance) = longest_path(&graph, start_pos, dest_pos, HashSet::new(), 0);
+    distance
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(&input.mtx).into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(&input.mtx).into())
+    }
+}
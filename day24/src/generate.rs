@@ -0,0 +1,56 @@
+//! Random hailstorms.
+
+use std::collections::HashSet;
+
+use aoc_core::{
+    generate::{Generator, RngExt, StdRng},
+    Answer,
+};
+
+/// Sized by the number of hailstones.
+pub const GENERATOR: Generator = Generator {
+    size: 300,
+    input,
+    answers: Some(answers),
+};
+
+/// Three random components in `range`, none of them zero.
+fn nonzero(rng: &mut StdRng, range: std::ops::RangeInclusive<i64>) -> [i64; 3] {
+    [(); 3].map(|()| loop {
+        let x = rng.random_range(range.clone());
+        if x != 0 {
+            break x;
+        }
+    })
+}
+
+fn input(rng: &mut StdRng, hailstones: usize) -> String {
+    hailstorm(rng, hailstones).0
+}
+
+/// Part 2 adds up the coordinates of the rock's starting position.
+fn answers(rng: &mut StdRng, hailstones: usize) -> [Option<Answer>; 2] {
+    [None, Some(hailstorm(rng, hailstones).1.into())]
+}
+
+/// Hailstones that a rock thrown from a random position hits one at a
+/// time, at different whole nanoseconds, and the sum of that position. At
+/// least three.
+fn hailstorm(rng: &mut StdRng, hailstones: usize) -> (String, i64) {
+    let hailstones = hailstones.max(3);
+    let rock = [(); 3].map(|()| rng.random_range(100_000_000_000_000..=500_000_000_000_000));
+    let rock_velocity = nonzero(rng, -300..=300);
+    let mut times = HashSet::new();
+    let mut s = String::new();
+    while times.len() < hailstones {
+        let time: i64 = rng.random_range(100_000_000_000..=1_000_000_000_000);
+        if !times.insert(time) {
+            continue;
+        }
+        let velocity = nonzero(rng, -300..=300);
+        let [x, y, z] = [0, 1, 2].map(|i| rock[i] + (rock_velocity[i] - velocity[i]) * time);
+        let [dx, dy, dz] = velocity;
+        s.push_str(&format!("{x}, {y}, {z} @ {dx}, {dy}, {dz}\n"));
+    }
+    (s, rock.iter().sum())
+}
@@ -0,0 +1,200 @@
+//! Day 24: Never Tell Me The Odds
+
+pub mod generate;
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use log::debug;
+use num::{BigInt, BigRational, ToPrimitive, Zero};
+
+use aoc_core::{
+    checked, geometry::Point3, params::Params, parse, Answer, Overflow, ParseError, PartError,
+    Solution,
+};
+
+pub type Point = Point3<i64>;
+pub type Vector = Point;
+
+/// Bounds of the part 1 test area, on both the x and y axes.
+const TEST_AREA: (f64, f64) = (200000000000000.0, 400000000000000.0);
+
+pub struct Input {
+    pub lines: Vec<(Point, Vector)>,
+    pub test_area: (f64, f64),
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s
+            .lines()
+            .map(|line| {
+                let (p, v) = parse::split_once(line, "@")?;
+                Ok((p.parse()?, v.parse()?))
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Input {
+            lines,
+            test_area: TEST_AREA,
+        })
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn intersection(
+    x0: f64,
+    y0: f64,
+    dx0: f64,
+    dy0: f64,
+    x1: f64,
+    y1: f64,
+    dx1: f64,
+    dy1: f64,
+) -> Option<(f64, f64)> {
+    if dx0 * dy1 == dx1 * dy0 {
+        return None;
+    }
+    let t0 = ((x1 - x0) * dy1 + (y0 - y1) * dx1) / (dx0 * dy1 - dx1 * dy0);
+    let t1 = (y0 - y1 + dy0 * t0) / dy1;
+    Some((t0, t1))
+}
+
+pub fn part1(input: &Input) -> usize {
+    let (low, high) = input.test_area;
+    input
+        .lines
+        .iter()
+        .combinations(2)
+        .filter(|lines| {
+            let &(p0, v0) = lines[0];
+            let &(p1, v1) = lines[1];
+            let [x0, y0, dx0, dy0] = [p0.x, p0.y, v0.x, v0.y].map(|c| c as f64);
+            let [x1, y1, dx1, dy1] = [p1.x, p1.y, v1.x, v1.y].map(|c| c as f64);
+            intersection(x0, y0, dx0, dy0, x1, y1, dx1, dy1).is_some_and(|(t0, t1)| {
+                let x = x0 + t0 * dx0;
+                let y = y0 + t0 * dy0;
+                x >= low && x <= high && y >= low && y <= high && t0 > 0.0 && t1 > 0.0
+            })
+        })
+        .count()
+}
+
+fn cross(a: Point, b: Point) -> [BigInt; 3] {
+    let [ax, ay, az] = [a.x, a.y, a.z].map(BigInt::from);
+    let [bx, by, bz] = [b.x, b.y, b.z].map(BigInt::from);
+    [
+        &ay * &bz - &az * &by,
+        &az * &bx - &ax * &bz,
+        ax * by - ay * bx,
+    ]
+}
+
+/// Three equations in the rock's `[x, y, z, dx, dy, dz]`, with the constant
+/// last, from `(P - Pi) × (V - Vi) = 0` for hailstone `i` minus the same for
+/// hailstone `j`, which cancels the `P × V` term.
+fn equations((pi, vi): (Point, Vector), (pj, vj): (Point, Vector)) -> [Vec<BigRational>; 3] {
+    let diff =
+        |a: Point, b: Point| [(a.x, b.x), (a.y, b.y), (a.z, b.z)].map(|(a, b)| BigInt::from(a) - b);
+    let [dx, dy, dz] = diff(vi, vj);
+    let [ex, ey, ez] = diff(pi, pj);
+    let [ci, cj] = [cross(pi, vi), cross(pj, vj)];
+    let [rx, ry, rz] = [0, 1, 2].map(|k| &ci[k] - &cj[k]);
+    let zero = BigInt::zero;
+    #[rustfmt::skip]
+    let rows = [
+        [zero(),       dz.clone(),  -dy.clone(), zero(),      -ez.clone(), ey.clone(), rx],
+        [-dz,          zero(),      dx.clone(),  ez,          zero(),      -ex.clone(), ry],
+        [dy,           -dx,         zero(),      -ey,         ex,          zero(),      rz],
+    ];
+    rows.map(|row| row.into_iter().map(BigRational::from_integer).collect())
+}
+
+/// Solves the equations by Gauss-Jordan elimination, or returns `None` if
+/// they do not fix a single solution.
+fn solve<const N: usize>(mut rows: Vec<Vec<BigRational>>) -> Option<[BigRational; N]> {
+    for col in 0..N {
+        let pivot = (col..rows.len()).find(|&row| !rows[row][col].is_zero())?;
+        rows.swap(col, pivot);
+        let lead = rows[col][col].clone();
+        rows[col].iter_mut().for_each(|coeff| *coeff /= &lead);
+        let pivot = rows[col].clone();
+        for (row, equation) in rows.iter_mut().enumerate() {
+            let factor = equation[col].clone();
+            if row == col || factor.is_zero() {
+                continue;
+            }
+            for (coeff, coeff0) in equation.iter_mut().zip(&pivot) {
+                *coeff -= &factor * coeff0;
+            }
+        }
+    }
+    if rows[N..].iter().any(|row| !row[N].is_zero()) {
+        return None;
+    }
+    Some(std::array::from_fn(|k| rows[k][N].clone()))
+}
+
+/// The rock's starting position, found exactly from the equations every pair
+/// with the first hailstone gives.
+pub fn rock(input: &Input) -> Result<Point, PartError> {
+    let (&first, rest) = input
+        .lines
+        .split_first()
+        .ok_or_else(|| PartError::no_answer("there are no hailstones"))?;
+    let rows = rest
+        .iter()
+        .flat_map(|&line| equations(first, line))
+        .collect_vec();
+    if rows.len() < 6 {
+        return Err(PartError::no_answer(
+            "the rock's path needs at least three hailstones",
+        ));
+    }
+    let [x, y, z, ..] = solve::<6>(rows)
+        .ok_or_else(|| PartError::no_answer("no single rock path hits every hailstone"))?;
+    let [x, y, z] = [x, y, z].map(|coord| coord.is_integer().then(|| coord.to_integer()));
+    let (Some(x), Some(y), Some(z)) = (x, y, z) else {
+        return Err(PartError::no_answer(
+            "the rock does not start at whole coordinates",
+        ));
+    };
+    match (x.to_i64(), y.to_i64(), z.to_i64()) {
+        (Some(x), Some(y), Some(z)) => Ok(Point { x, y, z }),
+        _ => Err(Overflow.into()),
+    }
+}
+
+pub fn part2(input: &Input) -> Result<i64, PartError> {
+    let rock = rock(input)?;
+    debug!("rock: {rock:?}");
+    Ok(checked::add(checked::add(rock.x, rock.y)?, rock.z)?)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input, ParseError> {
+        let mut input: Input = input.parse()?;
+        input.test_area = (
+            params.get("test_area_min", TEST_AREA.0)?,
+            params.get("test_area_max", TEST_AREA.1)?,
+        );
+        Ok(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_core::examples::check::<day24::Day24>(env!("CARGO_MANIFEST_DIR"));
+}
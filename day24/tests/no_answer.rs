@@ -0,0 +1,13 @@
+use aoc_core::{PartError, Solution};
+use day24::{Day24, Input};
+
+#[test]
+fn two_hailstones_do_not_fix_the_rock() {
+    let input: Input = "19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2\n"
+        .parse()
+        .unwrap();
+    assert!(matches!(
+        Day24::part2(&input),
+        Err(PartError::NoAnswer(reason)) if reason.contains("three hailstones")
+    ));
+}
@@ -0,0 +1,18 @@
+use aoc_core::Answer;
+use day24::{generate::GENERATOR, Input};
+
+#[test]
+fn part2_finds_the_rock_the_generator_threw() {
+    for size in [3, 4, 10, 30] {
+        for seed in 0..20 {
+            let text = GENERATOR.generate(seed, size);
+            let input: Input = text.parse().unwrap();
+            let [_, rock] = GENERATOR.answers(seed, size);
+            assert_eq!(
+                day24::part2(&input).map(Answer::from).ok(),
+                rock,
+                "seed {seed}, size {size}:\n{text}"
+            );
+        }
+    }
+}
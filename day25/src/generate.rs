@@ -0,0 +1,97 @@
+//! Random wiring diagrams.
+
+use aoc_core::{
+    generate::{self, Generator, RngExt, SliceRandom, StdRng},
+    Answer,
+};
+
+/// Sized by the number of components.
+pub const GENERATOR: Generator = Generator {
+    size: 1500,
+    input,
+    answers: Some(answers),
+};
+
+/// Wires joining `group` so that cutting fewer than four never splits it:
+/// the first five components are all wired to each other, and every later
+/// one to four earlier ones.
+fn wire_group(rng: &mut StdRng, group: &[usize], wires: &mut Vec<(usize, usize)>) {
+    for i in 1..group.len() {
+        let earlier: Vec<usize> = if i < 5 {
+            (0..i).collect()
+        } else {
+            four_below(rng, i)
+        };
+        wires.extend(earlier.into_iter().map(|j| (group[i], group[j])));
+    }
+}
+
+/// Four different indices below `i`.
+fn four_below(rng: &mut StdRng, i: usize) -> Vec<usize> {
+    let mut earlier = vec![];
+    while earlier.len() < 4 {
+        let j = rng.random_range(0..i);
+        if !earlier.contains(&j) {
+            earlier.push(j);
+        }
+    }
+    earlier
+}
+
+fn input(rng: &mut StdRng, components: usize) -> String {
+    wiring(rng, components).0
+}
+
+/// Cutting the three wires between the groups leaves them apart.
+fn answers(rng: &mut StdRng, components: usize) -> [Option<Answer>; 2] {
+    [Some(wiring(rng, components).1.into()), None]
+}
+
+/// Two groups of components, each splitting 40 to 60% of them, joined by
+/// exactly three wires, and the product of their sizes.
+fn wiring(rng: &mut StdRng, components: usize) -> (String, usize) {
+    let components = components.max(10);
+    let mut len = 1;
+    while 26usize.pow(len as u32) < 2 * components {
+        len += 1;
+    }
+    let names = generate::distinct_words(rng, components, len.max(3), &[]);
+    let first = rng
+        .random_range(components * 2 / 5..=components * 3 / 5)
+        .clamp(5, components - 5);
+    let mut order: Vec<usize> = (0..components).collect();
+    order.shuffle(rng);
+    let (a, b) = order.split_at(first);
+    let mut wires = vec![];
+    wire_group(rng, a, &mut wires);
+    wire_group(rng, b, &mut wires);
+    let mut bridges = vec![];
+    while bridges.len() < 3 {
+        let bridge = (
+            a[rng.random_range(0..a.len())],
+            b[rng.random_range(0..b.len())],
+        );
+        if !bridges.contains(&bridge) {
+            bridges.push(bridge);
+        }
+    }
+    wires.extend(bridges);
+    // List each wire once, under either of its ends.
+    let mut listed: Vec<Vec<usize>> = vec![vec![]; components];
+    for (x, y) in wires {
+        if rng.random_bool(0.5) {
+            listed[x].push(y);
+        } else {
+            listed[y].push(x);
+        }
+    }
+    let mut lines: Vec<String> = (0..components)
+        .filter(|&x| !listed[x].is_empty())
+        .map(|x| {
+            let others: Vec<&str> = listed[x].iter().map(|&y| names[y].as_str()).collect();
+            format!("{}: {}", names[x], others.join(" "))
+        })
+        .collect();
+    lines.shuffle(rng);
+    (lines.join("\n") + "\n", first * (components - first))
+}
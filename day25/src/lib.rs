@@ -0,0 +1,213 @@
+//! Day 25: Snowverload
+
+pub mod generate;
+
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+use aoc_core::{checked, parse, Answer, ParseError, PartError, Solution};
+use log::debug;
+
+/// The wiring as an undirected graph. Components are numbered in order of
+/// first appearance.
+pub struct Input {
+    pub names: Vec<String>,
+    /// Each wire once, as a pair of components.
+    pub wires: Vec<(usize, usize)>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut names = vec![];
+        let mut ids = HashMap::new();
+        let mut id = |name: &str| {
+            *ids.entry(name.to_owned()).or_insert_with(|| {
+                names.push(name.to_owned());
+                names.len() - 1
+            })
+        };
+        let mut wires = vec![];
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            let (component, others) = parse::split_once(line, ":")?;
+            let component = id(component.trim());
+            for other in others.split_whitespace() {
+                wires.push((component, id(other)));
+            }
+        }
+        Ok(Input { names, wires })
+    }
+}
+
+/// A partition of the components in two groups.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cut {
+    /// Whether each component is in the first group.
+    pub first: Vec<bool>,
+    /// Indices into [`Input::wires`] of the wires between the groups.
+    pub wires: Vec<usize>,
+}
+
+impl Cut {
+    pub fn group_sizes(&self) -> (usize, usize) {
+        let first = self.first.iter().filter(|&&first| first).count();
+        (first, self.first.len() - first)
+    }
+}
+
+/// Maximum flow through wires of capacity one, in either direction.
+struct Network<'a> {
+    wires: &'a [(usize, usize)],
+    /// For each component, its wires as (neighbour, wire index).
+    adjacent: Vec<Vec<(usize, usize)>>,
+    /// Flow along each wire, positive from its first component to its second.
+    flow: Vec<i32>,
+}
+
+impl<'a> Network<'a> {
+    fn new(components: usize, wires: &'a [(usize, usize)]) -> Self {
+        let mut adjacent = vec![vec![]; components];
+        for (i, &(a, b)) in wires.iter().enumerate() {
+            adjacent[a].push((b, i));
+            adjacent[b].push((a, i));
+        }
+        Network {
+            wires,
+            adjacent,
+            flow: vec![0; wires.len()],
+        }
+    }
+
+    /// Flow from `from` to its neighbour along `wire`.
+    fn flow_from(&self, from: usize, wire: usize) -> i32 {
+        if self.wires[wire].0 == from {
+            self.flow[wire]
+        } else {
+            -self.flow[wire]
+        }
+    }
+
+    /// Breadth-first search from `source` through wires with spare capacity,
+    /// recording the wire each component was reached by.
+    fn residual_bfs(&self, source: usize) -> Vec<Option<usize>> {
+        let mut via = vec![None; self.adjacent.len()];
+        let mut reached = vec![false; self.adjacent.len()];
+        reached[source] = true;
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            for &(next, wire) in &self.adjacent[v] {
+                if !reached[next] && self.flow_from(v, wire) < 1 {
+                    reached[next] = true;
+                    via[next] = Some(wire);
+                    queue.push_back(next);
+                }
+            }
+        }
+        via
+    }
+
+    /// Pushes flow from `source` to `sink` until no more fits or it reaches
+    /// `limit`. Returns the flow and, if it stayed below `limit`, the
+    /// components on the source's side of a minimum cut.
+    fn max_flow(&mut self, source: usize, sink: usize, limit: usize) -> (usize, Option<Vec<bool>>) {
+        self.flow.fill(0);
+        let mut total = 0;
+        while total < limit {
+            let via = self.residual_bfs(source);
+            if via[sink].is_none() {
+                let side = (0..via.len())
+                    .map(|v| v == source || via[v].is_some())
+                    .collect();
+                return (total, Some(side));
+            }
+            let mut v = sink;
+            while v != source {
+                let wire = via[v].unwrap();
+                let (a, b) = self.wires[wire];
+                if a == v {
+                    self.flow[wire] -= 1;
+                    v = b;
+                } else {
+                    self.flow[wire] += 1;
+                    v = a;
+                }
+            }
+            total += 1;
+        }
+        (total, None)
+    }
+}
+
+/// The cut through the fewest wires: the smallest of the minimum cuts
+/// between component 0 and each of the others. `None` if there are fewer
+/// than two components.
+pub fn min_cut(input: &Input) -> Option<Cut> {
+    let components = input.names.len();
+    let mut network = Network::new(components, &input.wires);
+    let mut best: Option<Vec<bool>> = None;
+    let mut best_size = usize::MAX;
+    for sink in 1..components {
+        if let (size, Some(first)) = network.max_flow(0, sink, best_size) {
+            best_size = size;
+            best = Some(first);
+        }
+    }
+    let first = best?;
+    let wires = (0..input.wires.len())
+        .filter(|&i| {
+            let (a, b) = input.wires[i];
+            first[a] != first[b]
+        })
+        .collect();
+    Some(Cut { first, wires })
+}
+
+/// The wires of the cut, as `a/b` pairs of component names.
+pub fn cut_wires(input: &Input, cut: &Cut) -> Vec<String> {
+    cut.wires
+        .iter()
+        .map(|&wire| {
+            let (a, b) = input.wires[wire];
+            format!("{}/{}", input.names[a], input.names[b])
+        })
+        .collect()
+}
+
+pub fn part1(input: &Input) -> Result<usize, PartError> {
+    let cut = min_cut(input)
+        .ok_or_else(|| PartError::no_answer("the wiring needs at least two components"))?;
+    debug!("cut {}", cut_wires(input, &cut).join(", "));
+    let (a, b) = cut.group_sizes();
+    Ok(checked::mul(a, b)?)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Input;
+    /// There is no second puzzle on the last day.
+    const PART2: bool = false;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(_input: &Input) -> Result<Answer, PartError> {
+        Err(PartError::no_answer("there is no second part"))
+    }
+
+    /// The wires to disconnect.
+    fn notes(input: &Input, part: u8) -> Vec<String> {
+        match (part, min_cut(input)) {
+            (1, Some(cut)) => cut_wires(input, &cut),
+            _ => vec![],
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_core::{PartError, Solution};
+use day25::{Day25, Input};
+
+#[test]
+fn notes_name_the_cut_wires() {
+    let input: Input = "a: b c\nb: c\nc: d\nd: e f\ne: f\n".parse().unwrap();
+    assert_eq!(Day25::part1(&input), Ok(9usize.into()));
+    assert_eq!(Day25::notes(&input, 1), ["c/d"]);
+    assert!(Day25::notes(&input, 2).is_empty());
+}
+
+#[test]
+fn too_few_components_have_no_answer() {
+    for text in ["", "a: \n"] {
+        let input: Input = text.parse().unwrap();
+        assert!(
+            matches!(Day25::part1(&input), Err(PartError::NoAnswer(_))),
+            "{text:?}"
+        );
+        assert!(Day25::notes(&input, 1).is_empty());
+    }
+}
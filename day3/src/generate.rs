@@ -0,0 +1,40 @@
+//! Random engine schematics.
+
+use aoc_core::generate::{Generator, IndexedRandom, RngExt, StdRng};
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '&', '-'];
+
+/// Sized by the side of the schematic.
+pub const GENERATOR: Generator = Generator {
+    size: 140,
+    input,
+    answers: None,
+};
+
+/// A square schematic of numbers of up to three digits and scattered symbols.
+/// Numbers touch symbols, other numbers and the edges in every way.
+fn input(rng: &mut StdRng, side: usize) -> String {
+    let mut rows = vec![vec!['.'; side]; side];
+    for row in &mut rows {
+        let mut c = 0;
+        while c < side {
+            let len = rng.random_range(1..=3).min(side - c);
+            if rng.random_bool(0.08) {
+                row[c] = char::from(rng.random_range(b'1'..=b'9'));
+                for cell in &mut row[c + 1..c + len] {
+                    *cell = char::from(rng.random_range(b'0'..=b'9'));
+                }
+                // Keep numbers apart, or they would read as one.
+                c += len + 1;
+            } else {
+                if rng.random_bool(0.05) {
+                    row[c] = *SYMBOLS.choose(rng).unwrap();
+                }
+                c += 1;
+            }
+        }
+    }
+    rows.into_iter()
+        .map(|row| row.into_iter().chain(['\n']).collect::<String>())
+        .collect()
+}
@@ -0,0 +1,120 @@
+//! Day 3: Gear Ratios
+
+pub mod generate;
+
+use aoc_core::{
+    checked::{self, CheckedIterator},
+    geometry::Pos,
+    grid::Grid,
+    Answer, Overflow, ParseError, PartError, Solution,
+};
+use itertools::Itertools;
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+fn get(mtx: &Grid<char>, r: isize, c: isize) -> char {
+    mtx.get_or(Pos { r, c }, '.')
+}
+
+fn ctoi(c: char) -> i64 {
+    c as i64 - '0' as i64
+}
+
+pub fn part1(mtx: &Grid<char>) -> Result<i64, Overflow> {
+    let mut total = 0;
+    let num_rows = mtx.height() as isize;
+    let num_cols = mtx.width() as isize;
+    for row in 0..num_rows {
+        let mut number = 0;
+        let mut is_valid = false;
+        for col in 0..num_cols {
+            let c = get(mtx, row, col);
+            if c.is_ascii_digit() {
+                number = checked::add(checked::mul(number, 10)?, ctoi(c))?;
+                is_valid |= (row - 1..=row + 1)
+                    .cartesian_product(col - 1..=col + 1)
+                    .map(|(r, c)| get(mtx, r, c))
+                    .any(|ch| ch != '.' && !ch.is_ascii_digit());
+            } else if number != 0 {
+                if is_valid {
+                    total = checked::add(total, number)?;
+                }
+                number = 0;
+                is_valid = false;
+            }
+        }
+        if is_valid {
+            total = checked::add(total, number)?;
+        }
+    }
+    Ok(total)
+}
+
+pub fn part2(mtx: &Grid<char>) -> Result<i64, Overflow> {
+    let mut numbers_per_geer: HashMap<(isize, isize), Vec<i64>> = HashMap::new();
+    let num_rows = mtx.height() as isize;
+    let num_cols = mtx.width() as isize;
+    for row in 0..num_rows {
+        let mut number = 0;
+        let mut geers = HashSet::new();
+        for col in 0..num_cols {
+            let c = get(mtx, row, col);
+            if c.is_ascii_digit() {
+                number = checked::add(checked::mul(number, 10)?, ctoi(c))?;
+                geers.extend(
+                    (row - 1..=row + 1)
+                        .cartesian_product(col - 1..=col + 1)
+                        .filter(|&(r, c)| get(mtx, r, c) == '*'),
+                );
+            } else if number != 0 {
+                for &geer in &geers {
+                    numbers_per_geer.entry(geer).or_default().push(number);
+                }
+                geers.clear();
+                number = 0;
+            }
+        }
+        for geer in geers {
+            numbers_per_geer.entry(geer).or_default().push(number);
+        }
+    }
+    numbers_per_geer
+        .values()
+        .filter_map(|numbers| match &numbers[..] {
+            [a, b] => Some(checked::mul(*a, *b)),
+            _ => None,
+        })
+        .try_checked_sum()
+}
+
+pub struct Input {
+    pub mtx: Grid<char>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Input { mtx: s.parse()? })
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(&input.mtx)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(&input.mtx)?.into())
+    }
+}
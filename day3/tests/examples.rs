@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_core::examples::check::<day3::Day3>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,70 @@
+//! Random scratchcards.
+
+use aoc_core::generate::{Generator, RngExt, SliceRandom, StdRng};
+
+const WINNING: usize = 10;
+const HAVE: usize = 25;
+
+/// Sized by the number of cards.
+pub const GENERATOR: Generator = Generator {
+    size: 213,
+    input,
+    answers: None,
+};
+
+/// How many of a card's numbers match. Most cards match none and few match
+/// many, so that the number of copies in part 2 grows with the number of
+/// cards rather than exponentially.
+fn matches(rng: &mut StdRng) -> usize {
+    if rng.random_bool(0.6) {
+        0
+    } else if rng.random_bool(0.15) {
+        rng.random_range(1..=WINNING)
+    } else {
+        rng.random_range(1..=3)
+    }
+}
+
+/// Cards with 10 winning numbers and 25 numbers you have. Matches on the
+/// last cards may point past the end of the table.
+fn input(rng: &mut StdRng, cards: usize) -> String {
+    let width = cards.to_string().len();
+    let mut s = String::new();
+    for id in 1..=cards {
+        let mut numbers: Vec<u32> = (1..100).collect();
+        numbers.shuffle(rng);
+        let winning = &numbers[..WINNING];
+        let matches = matches(rng);
+        let mut have: Vec<u32> = winning[..matches]
+            .iter()
+            .chain(&numbers[WINNING..WINNING + HAVE - matches])
+            .copied()
+            .collect();
+        have.shuffle(rng);
+        let show = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        s.push_str(&format!(
+            "Card {id:>width$}: {} | {}\n",
+            show(winning),
+            show(&have)
+        ));
+    }
+    s
+}
+
+/// `cards` cards that each win a copy of the next ten, so that the number of
+/// copies nearly doubles with every card and soon outgrows any integer.
+pub fn cascade(cards: usize) -> String {
+    let numbers = (1..=WINNING)
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (1..=cards)
+        .map(|id| format!("Card {id}: {numbers} | {numbers}\n"))
+        .collect()
+}
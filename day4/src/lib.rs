@@ -0,0 +1,97 @@
+//! Day 4: Scratchcards
+
+pub mod generate;
+
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_core::{
+    checked::{self, CheckedIterator},
+    parse, Answer, Overflow, ParseError, PartError, Solution,
+};
+
+fn parse_numbers(numbers: &str) -> Result<HashSet<u32>, ParseError> {
+    numbers.split_whitespace().map(parse::number).collect()
+}
+
+pub fn parse_card(line: &str) -> Result<(u32, HashSet<u32>, HashSet<u32>), ParseError> {
+    let (card, numbers) = parse::split_once(line, ":")?;
+    let id = parse::number(parse::strip_prefix(card, "Card")?.trim_start())?;
+    let (winning, found) = parse::split_once(numbers, "|")?;
+    Ok((id, parse_numbers(winning)?, parse_numbers(found)?))
+}
+
+pub fn part1(input: &[(u32, HashSet<u32>, HashSet<u32>)]) -> Result<u64, Overflow> {
+    input
+        .iter()
+        .map(|(_, winning, found)| {
+            found
+                .iter()
+                .filter(|found_number| winning.contains(found_number))
+                .count() as u32
+        })
+        .map(|n| match n {
+            0 => Ok(0),
+            _ => 2u64.checked_pow(n - 1).ok_or(Overflow),
+        })
+        .try_checked_sum()
+}
+
+pub fn part2(input: &[(u32, HashSet<u32>, HashSet<u32>)]) -> Result<u64, Overflow> {
+    let mut cards = vec![1u64; input.len()];
+    input
+        .iter()
+        .map(|(id, winning, found)| {
+            let num_matches = found
+                .iter()
+                .filter(|found_number| winning.contains(found_number))
+                .count();
+            (*id as usize, num_matches)
+        })
+        .try_for_each(|(id, num)| {
+            // Copies past the end of the table are lost.
+            for idx in id..(id + num).min(cards.len()) {
+                cards[idx] = checked::add(cards[idx], cards[id - 1])?;
+            }
+            Ok(())
+        })?;
+    cards.into_iter().checked_sum()
+}
+
+pub struct Input {
+    pub cards: Vec<(u32, HashSet<u32>, HashSet<u32>)>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = s.lines().map(parse_card).collect::<Result<Vec<_>, _>>()?;
+        // Part 2 uses card numbers as positions in the table.
+        for ((id, _, _), (line, expected)) in cards.iter().zip(s.lines().zip(1..)) {
+            if *id != expected {
+                let (card, _) = parse::split_once(line, ":")?;
+                let number = card.trim_start_matches("Card").trim_start();
+                return Err(ParseError::expected(format!("card {expected}"), number));
+            }
+        }
+        Ok(Input { cards })
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(&input.cards)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(&input.cards)?.into())
+    }
+}
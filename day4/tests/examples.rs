@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_core::examples::check::<day4::Day4>(env!("CARGO_MANIFEST_DIR"));
+}
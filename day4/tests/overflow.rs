@@ -0,0 +1,46 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use aoc_core::{Answer, PartError, Solution};
+use day4::{generate::cascade, Day4, Input};
+
+#[test]
+fn copies_beyond_u32() {
+    let input: Input = cascade(40).parse().unwrap();
+    let copies = Day4::part2(&input);
+    assert!(
+        matches!(copies, Ok(Answer::Unsigned(n)) if n > u64::from(u32::MAX)),
+        "{copies:?}"
+    );
+}
+
+#[test]
+fn copies_beyond_u64_overflow() {
+    let input: Input = cascade(100).parse().unwrap();
+    assert_eq!(Day4::part1(&input), Ok(51200u64.into()));
+    assert_eq!(Day4::part2(&input), Err(PartError::Overflow));
+}
+
+#[test]
+fn binary_names_the_day_and_part() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_day4"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(cascade(100).as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: day 4 part 2: arithmetic overflow\n"
+    );
+}
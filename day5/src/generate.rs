@@ -0,0 +1,59 @@
+//! Random almanacs.
+
+use aoc_core::generate::{Generator, RngExt, SliceRandom, StdRng};
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+/// Numbers in the almanac stay below this.
+const LIMIT: i64 = 1 << 32;
+
+/// Sized by the number of ranges in each map.
+pub const GENERATOR: Generator = Generator {
+    size: 30,
+    input,
+    answers: None,
+};
+
+fn input(rng: &mut StdRng, ranges: usize) -> String {
+    almanac(rng, ranges, LIMIT)
+}
+
+/// An almanac of numbers below `limit` whose maps shuffle adjacent source
+/// ranges, with gaps between some of them, into a contiguous block
+/// elsewhere.
+pub fn almanac(rng: &mut StdRng, ranges: usize, limit: i64) -> String {
+    let ranges = ranges.max(1);
+    let seeds: Vec<String> = (0..ranges.div_ceil(3))
+        .map(|_| {
+            let len = rng.random_range(1..=(limit >> 4).max(1));
+            format!("{} {len}", rng.random_range(0..limit - len))
+        })
+        .collect();
+    let mut s = format!("seeds: {}\n", seeds.join(" "));
+    for name in MAPS {
+        let mut cuts: Vec<i64> = (0..=ranges).map(|_| rng.random_range(0..limit)).collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+        let mut sources: Vec<(i64, i64)> = cuts
+            .windows(2)
+            .map(|pair| (pair[0], pair[1] - pair[0]))
+            .filter(|_| rng.random_bool(0.9))
+            .collect();
+        let total: i64 = sources.iter().map(|&(_, len)| len).sum();
+        let mut dst = rng.random_range(0..=limit - total);
+        sources.shuffle(rng);
+        s.push_str(&format!("\n{name} map:\n"));
+        for (src, len) in sources {
+            s.push_str(&format!("{dst} {src} {len}\n"));
+            dst += len;
+        }
+    }
+    s
+}
@@ -0,0 +1,153 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+pub mod generate;
+pub mod naive;
+
+use std::str::FromStr;
+
+use std::collections::{btree_map::Entry, BTreeMap};
+use std::ops::{Bound::Included, Bound::Unbounded};
+
+use aoc_core::{checked, parse, Answer, Overflow, ParseError, PartError, Solution};
+use log::trace;
+
+pub struct Input {
+    pub seeds: Vec<i64>,
+    pub maps: Vec<BTreeMap<i64, i64>>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let seeds = parse::strip_prefix(lines.next().unwrap_or(s), "seeds: ")?
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<Vec<i64>, _>>()?;
+        lines.next();
+        let mut maps: Vec<BTreeMap<i64, i64>> = Vec::new();
+        let mut map = BTreeMap::from([(0, 0)]);
+        for line in lines {
+            match line {
+                line if line.ends_with("map:") => {
+                    map.clear();
+                    map.insert(0, 0);
+                }
+                "" => {
+                    if !map.is_empty() {
+                        maps.push(map);
+                        map = BTreeMap::from([(0, 0)]);
+                    }
+                }
+                line => {
+                    let mapping_numbers = line
+                        .split_whitespace()
+                        .map(parse::number)
+                        .collect::<Result<Vec<i64>, _>>()?;
+                    let [dst, src, length] = mapping_numbers[..] else {
+                        return Err(ParseError::expected("three numbers", line));
+                    };
+                    let too_big = |_| ParseError::expected("a mapping within 64 bits", line);
+                    let diff = checked::sub(dst, src).map_err(too_big)?;
+                    let end = checked::add(src, length).map_err(too_big)?;
+                    map.insert(src, diff);
+                    if let Entry::Vacant(end) = map.entry(end) {
+                        end.insert(0);
+                    }
+                }
+            }
+        }
+        if !map.is_empty() {
+            maps.push(map);
+        }
+        Ok(Self { seeds, maps })
+    }
+}
+
+pub fn do_map(map: &BTreeMap<i64, i64>, key: i64) -> Result<i64, Overflow> {
+    let diff = map.range((Unbounded, Included(&key))).last().unwrap().1;
+    checked::add(key, *diff)
+}
+
+pub fn do_map_range(
+    map: &BTreeMap<i64, i64>,
+    key_ranges: Vec<(i64, i64)>,
+) -> Result<Vec<(i64, i64)>, Overflow> {
+    trace!("map: {map:?}, key_ranges: {key_ranges:?}");
+    let mut result = Vec::new();
+    for (key_begin, key_end) in key_ranges {
+        let mut src_begin = key_begin;
+        let mut curr_diff = do_map(map, key_begin)? - key_begin;
+        for (&src_end, &diff) in map.range((Included(&key_begin), Included(&key_end))) {
+            if src_end == src_begin {
+                continue;
+            }
+            result.push((
+                checked::add(src_begin, curr_diff)?,
+                checked::add(src_end, curr_diff)?,
+            ));
+            src_begin = src_end;
+            curr_diff = diff;
+        }
+        if src_begin != key_end {
+            result.push((
+                checked::add(src_begin, curr_diff)?,
+                checked::add(key_end, curr_diff)?,
+            ));
+        }
+    }
+    trace!("mapped ranges: {result:?}");
+    Ok(result)
+}
+
+pub fn part1(input: &Input) -> Result<i64, PartError> {
+    let mut lowest = None;
+    for &seed in &input.seeds {
+        let location = input
+            .maps
+            .iter()
+            .try_fold(seed, |src, map| do_map(map, src))?;
+        lowest = Some(lowest.map_or(location, |lowest: i64| lowest.min(location)));
+    }
+    lowest.ok_or_else(|| PartError::no_answer("there are no seeds"))
+}
+
+pub fn part2(input: &Input) -> Result<i64, PartError> {
+    if !input.seeds.len().is_multiple_of(2) {
+        return Err(PartError::no_answer(
+            "the seeds do not pair up into starts and lengths",
+        ));
+    }
+    let mut lowest = None;
+    for range in input.seeds.chunks(2) {
+        let (begin, length) = (range[0], range[1]);
+        let end = checked::sub(checked::add(begin, length)?, 1)?;
+        let ranges = input
+            .maps
+            .iter()
+            .try_fold(vec![(begin, end)], |ranges, map| do_map_range(map, ranges))?;
+        for (range_start, _) in ranges {
+            lowest = Some(lowest.map_or(range_start, |lowest: i64| lowest.min(range_start)));
+        }
+    }
+    lowest.ok_or_else(|| PartError::no_answer("there are no seeds"))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
@@ -0,0 +1,21 @@
+//! Straightforward solutions to check the optimised ones against.
+
+use aoc_core::Overflow;
+
+use crate::{do_map, Input};
+
+/// The lowest location of any seed in the ranges, mapping every seed on its
+/// own rather than whole ranges at once.
+pub fn part2(input: &Input) -> Result<i64, Overflow> {
+    let mut lowest = i64::MAX;
+    for range in input.seeds.chunks(2) {
+        for seed in range[0]..range[0] + range[1] {
+            let location = input
+                .maps
+                .iter()
+                .try_fold(seed, |src, map| do_map(map, src))?;
+            lowest = lowest.min(location);
+        }
+    }
+    Ok(lowest)
+}
@@ -0,0 +1,19 @@
+use aoc_core::{
+    generate::{SeedableRng, StdRng},
+    PartError,
+};
+use day5::{generate, naive, Input};
+
+#[test]
+fn range_mapping_matches_mapping_every_seed() {
+    for seed in 0..50 {
+        let ranges = 1 + seed as usize % 8;
+        let text = generate::almanac(&mut StdRng::seed_from_u64(seed), ranges, 1000);
+        let input: Input = text.parse().unwrap();
+        assert_eq!(
+            day5::part2(&input),
+            naive::part2(&input).map_err(PartError::from),
+            "seed {seed}:\n{text}"
+        );
+    }
+}
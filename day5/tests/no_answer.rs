@@ -0,0 +1,23 @@
+use aoc_core::PartError;
+use day5::Input;
+
+#[test]
+fn no_seeds_have_no_lowest_location() {
+    let input: Input = "seeds: \n\nseed-to-soil map:\n50 98 2\n".parse().unwrap();
+    let no_seeds = PartError::no_answer("there are no seeds");
+    assert_eq!(day5::part1(&input), Err(no_seeds.clone()));
+    assert_eq!(day5::part2(&input), Err(no_seeds));
+}
+
+#[test]
+fn part2_reads_seeds_in_pairs() {
+    let input: Input = "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        day5::part2(&input),
+        Err(PartError::no_answer(
+            "the seeds do not pair up into starts and lengths"
+        ))
+    );
+}
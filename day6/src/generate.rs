@@ -0,0 +1,54 @@
+//! Random race sheets.
+
+use aoc_core::generate::{Generator, RngExt, StdRng};
+
+/// Sized by the number of races. Part 2 joins every race into one, so past
+/// nine races its time no longer fits in 64 bits.
+pub const GENERATOR: Generator = Generator {
+    size: 4,
+    input,
+    answers: None,
+};
+
+/// Races that can all be won, including the one part 2 reads from the
+/// joined digits.
+fn input(rng: &mut StdRng, races: usize) -> String {
+    let races = races.max(1);
+    // Keep the joined time within nine digits while there are few races.
+    let digits = (9 / races).clamp(1, 2) as u32;
+    loop {
+        let times: Vec<u64> = (0..races)
+            .map(|_| rng.random_range(2.max(10u64.pow(digits - 1))..10u64.pow(digits)))
+            .collect();
+        let distances: Vec<u64> = times
+            .iter()
+            .map(|&time| {
+                let record = (time / 2) * (time - time / 2);
+                rng.random_range(record / 2..record)
+            })
+            .collect();
+        let joined = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(u64::to_string)
+                .collect::<String>()
+                .parse::<u128>()
+                .unwrap_or(u128::MAX)
+        };
+        let (time, distance) = (joined(&times), joined(&distances));
+        if (time / 2) * (time - time / 2) <= distance {
+            continue;
+        }
+        let column = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>5}"))
+                .collect::<String>()
+        };
+        return format!(
+            "Time:    {}\nDistance:{}\n",
+            column(&times),
+            column(&distances)
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Day 6: Wait For It
+
+pub mod generate;
+pub mod naive;
+
+use std::str::FromStr;
+
+use aoc_core::{checked, parse, Answer, Overflow, ParseError, PartError, Solution};
+use log::debug;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Race {
+    pub time: i64,
+    pub distance: i64,
+}
+
+pub struct Input {
+    pub races: Vec<Race>,
+    pub one_race: Race,
+}
+
+/// The numbers after `label` on `line`, along with all of their digits
+/// read as one number.
+fn parse_line(line: Option<&str>, label: &str, input: &str) -> Result<(Vec<i64>, i64), ParseError> {
+    let line =
+        line.ok_or_else(|| ParseError::expected(format!("`{label}`"), &input[input.len()..]))?;
+    let numbers = parse::strip_prefix(line, label)?;
+    let separate = numbers
+        .split_whitespace()
+        .map(parse::number)
+        .collect::<Result<Vec<_>, _>>()?;
+    let joined = numbers
+        .split_whitespace()
+        .collect::<String>()
+        .parse()
+        .map_err(|_| {
+            ParseError::expected("digits that join into a 64-bit number", numbers.trim())
+        })?;
+    Ok((separate, joined))
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let (times, one_time) = parse_line(lines.next(), "Time:", s)?;
+        let distances_line = lines.next();
+        let (distances, one_distance) = parse_line(distances_line, "Distance:", s)?;
+        if distances.len() != times.len() {
+            let distances_line = distances_line.unwrap_or_default();
+            return Err(ParseError::expected(
+                format!("{} distances", times.len()),
+                distances_line,
+            ));
+        }
+        Ok(Input {
+            races: times
+                .into_iter()
+                .zip(distances)
+                .map(|(t, d)| Race {
+                    time: t,
+                    distance: d,
+                })
+                .collect(),
+            one_race: Race {
+                time: one_time,
+                distance: one_distance,
+            },
+        })
+    }
+}
+
+fn partition_point(
+    (begin, end): (i64, i64),
+    pred: impl Fn(i64) -> Result<bool, Overflow>,
+) -> Result<i64, Overflow> {
+    if begin == end {
+        return Ok(end);
+    }
+    let mid = begin + (end - begin) / 2;
+    if pred(mid)? {
+        return partition_point((begin, mid), pred);
+    }
+    partition_point((mid + 1, end), pred)
+}
+
+pub fn number_of_ways_to_win(race: Race) -> Result<i64, Overflow> {
+    let half_time = race.time / 2;
+    let min_press_time = partition_point((0, checked::add(half_time, 1)?), |press_time| {
+        Ok(checked::mul(press_time, race.time - press_time)? > race.distance)
+    })?;
+    let num_ways_half = half_time - min_press_time + 1;
+    let result = if num_ways_half == 0 {
+        // Not even holding for half the race beats the record.
+        0
+    } else if race.time % 2 == 0 {
+        checked::mul(num_ways_half, 2)? - 1
+    } else {
+        checked::mul(num_ways_half, 2)?
+    };
+    debug!("race: {race:?}, num_ways: {result}");
+    Ok(result)
+}
+
+pub fn part1(input: &Input) -> Result<i64, Overflow> {
+    input.races.iter().try_fold(1, |ways, &race| {
+        checked::mul(ways, number_of_ways_to_win(race)?)
+    })
+}
+
+pub fn part2(input: &Input) -> Result<i64, Overflow> {
+    number_of_ways_to_win(input.one_race)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
@@ -0,0 +1,25 @@
+//! Straightforward solutions to check the optimised ones against.
+
+use aoc_core::{checked::CheckedIterator, Overflow};
+
+use crate::{Input, Race};
+
+/// Tries holding the button for every millisecond of the race.
+pub fn number_of_ways_to_win(race: Race) -> i64 {
+    (0..=race.time)
+        .filter(|&press_time| press_time * (race.time - press_time) > race.distance)
+        .count() as i64
+}
+
+pub fn part1(input: &Input) -> Result<i64, Overflow> {
+    input
+        .races
+        .iter()
+        .copied()
+        .map(number_of_ways_to_win)
+        .checked_product()
+}
+
+pub fn part2(input: &Input) -> i64 {
+    number_of_ways_to_win(input.one_race)
+}
@@ -0,0 +1,4 @@
+#[test]
+fn examples() {
+    aoc_core::examples::check::<day6::Day6>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,38 @@
+use day6::{generate::GENERATOR, naive, Input};
+
+#[test]
+fn binary_search_matches_trying_every_millisecond() {
+    for seed in 0..30 {
+        // Up to three races, which join into a race of under a million
+        // milliseconds.
+        let races = 1 + seed as usize % 3;
+        let text = GENERATOR.generate(seed, races);
+        let input: Input = text.parse().unwrap();
+        assert_eq!(
+            day6::part1(&input),
+            naive::part1(&input),
+            "seed {seed}:\n{text}"
+        );
+        assert_eq!(
+            day6::part2(&input),
+            Ok(naive::part2(&input)),
+            "seed {seed}:\n{text}"
+        );
+    }
+}
+
+#[test]
+fn binary_search_matches_on_every_small_race() {
+    use day6::Race;
+
+    for time in 0..30 {
+        for distance in 0..250 {
+            let race = Race { time, distance };
+            assert_eq!(
+                day6::number_of_ways_to_win(race),
+                Ok(naive::number_of_ways_to_win(race)),
+                "{race:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Random sets of hands.
+
+use std::collections::HashSet;
+
+use aoc_core::generate::{Generator, IndexedRandom, RngExt, StdRng};
+
+const CARDS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+/// Sized by the number of hands.
+pub const GENERATOR: Generator = Generator {
+    size: 1000,
+    input,
+    answers: None,
+};
+
+/// Different hands with bids of up to 1000. Hands are drawn from a few
+/// cards at a time, so that every type of hand turns up.
+fn input(rng: &mut StdRng, hands: usize) -> String {
+    let hands = hands.min(CARDS.len().pow(5));
+    let mut seen = HashSet::new();
+    let mut s = String::new();
+    while seen.len() < hands {
+        let kinds = rng.random_range(1..=5);
+        let deck: Vec<char> = CARDS.sample(rng, kinds).copied().collect();
+        let hand: String = (0..5).map(|_| *deck.choose(rng).unwrap()).collect();
+        if seen.insert(hand.clone()) {
+            s.push_str(&format!("{hand} {}\n", rng.random_range(1..=1000)));
+        }
+    }
+    s
+}
@@ -0,0 +1,195 @@
+//! Day 7: Camel Cards
+
+pub mod generate;
+
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_core::{
+    checked::{self, CheckedIterator},
+    parse, Answer, Overflow, ParseError, PartError, Solution,
+};
+
+const J: char = 'J';
+
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct Card {
+    pub rank: i32,
+}
+
+impl Card {
+    pub fn new(card: char, jokers: bool) -> Self {
+        let rank = match card {
+            '2'..='9' => card as i32 - '0' as i32,
+            'T' => 10,
+            'J' if jokers => 1,
+            'J' => 11,
+            'Q' => 12,
+            'K' => 13,
+            'A' => 14,
+            _ => unreachable!("cards are checked while parsing"),
+        };
+        Card { rank }
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq)]
+#[allow(dead_code)]
+pub enum HandStrength {
+    High,
+    Pair,
+    TwoPair,
+    Three,
+    FullHouse,
+    Four,
+    Five,
+}
+
+impl HandStrength {
+    pub fn new(hand: &str, jokers: bool) -> Self {
+        let mut sorted_hand: Vec<(char, i32)> = hand
+            .chars()
+            .fold(HashMap::new(), |mut acc, c| {
+                acc.entry(c).and_modify(|cnt| *cnt += 1).or_insert(1);
+                acc
+            })
+            .into_iter()
+            .collect();
+        sorted_hand.sort_by(|(lcard, lnum), (rcard, rnum)| rnum.cmp(lnum).then(rcard.cmp(lcard)));
+        if jokers {
+            if let Some(strength) = Self::with_jokers(&sorted_hand) {
+                return strength;
+            }
+        }
+        Self::without_jokers(&sorted_hand)
+    }
+
+    fn with_jokers(sorted_hand: &[(char, i32)]) -> Option<Self> {
+        match sorted_hand {
+            [(J, 4), (_, 1)] => Some(Self::Five),
+            [(_, 4), (J, 1)] => Some(Self::Five),
+
+            [(J, 3), (_, 2)] => Some(Self::Five),
+            [(_, 3), (J, 2)] => Some(Self::Five),
+
+            [(J, 3), (_, 1), (_, 1)] => Some(Self::Four),
+            [(_, 3), (J, 1), (_, 1)] => Some(Self::Four),
+            [(_, 3), (_, 1), (J, 1)] => Some(Self::Four),
+
+            [(J, 2), (_, 2), (_, 1)] => Some(Self::Four),
+            [(_, 2), (J, 2), (_, 1)] => Some(Self::Four),
+            [(_, 2), (_, 2), (J, 1)] => Some(Self::FullHouse),
+
+            [(J, 2), (_, 1), (_, 1), (_, 1)] => Some(Self::Three),
+            [(_, 2), (J, 1), (_, 1), (_, 1)] => Some(Self::Three),
+            [(_, 2), (_, 1), (J, 1), (_, 1)] => Some(Self::Three),
+            [(_, 2), (_, 1), (_, 1), (J, 1)] => Some(Self::Three),
+
+            [(J, 1), (_, 1), (_, 1), (_, 1), (_, 1)] => Some(Self::Pair),
+            [(_, 1), (J, 1), (_, 1), (_, 1), (_, 1)] => Some(Self::Pair),
+            [(_, 1), (_, 1), (J, 1), (_, 1), (_, 1)] => Some(Self::Pair),
+            [(_, 1), (_, 1), (_, 1), (J, 1), (_, 1)] => Some(Self::Pair),
+            [(_, 1), (_, 1), (_, 1), (_, 1), (J, 1)] => Some(Self::Pair),
+            _ => None,
+        }
+    }
+
+    fn without_jokers(sorted_hand: &[(char, i32)]) -> Self {
+        match sorted_hand {
+            [(_, 5)] => Self::Five,
+            [(_, 4), (_, 1)] => Self::Four,
+            [(_, 3), (_, 2)] => Self::FullHouse,
+            [(_, 3), (_, 1), (_, 1)] => Self::Three,
+            [(_, 2), (_, 2), (_, 1)] => Self::TwoPair,
+            [(_, 2), (_, 1), (_, 1), (_, 1)] => Self::Pair,
+            [(_, 1), (_, 1), (_, 1), (_, 1), (_, 1)] => Self::High,
+            _ => unreachable!("hands have five cards"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    pub strength: HandStrength,
+    pub cards: Vec<Card>,
+}
+
+impl Hand {
+    pub fn new(hand: &str, jokers: bool) -> Self {
+        Hand {
+            cards: hand.chars().map(|card| Card::new(card, jokers)).collect(),
+            strength: HandStrength::new(hand, jokers),
+        }
+    }
+}
+
+/// Card labels from weakest to strongest.
+const CARDS: &str = "23456789TJQKA";
+
+fn parse_hand(line: &str) -> Result<(String, i64), ParseError> {
+    let (hand, bid) = parse::split_once(line, " ")?;
+    if hand.chars().count() != 5 || !hand.chars().all(|card| CARDS.contains(card)) {
+        return Err(ParseError::expected(
+            format!("five cards out of `{CARDS}`"),
+            hand,
+        ));
+    }
+    Ok((hand.to_owned(), parse::number(bid)?))
+}
+
+pub struct Input {
+    pub hands_to_bid: Vec<(String, i64)>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Input {
+            hands_to_bid: s.lines().map(parse_hand).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+pub fn total_winnings(input: &Input, jokers: bool) -> Result<i64, Overflow> {
+    let mut hands_to_bid = input
+        .hands_to_bid
+        .iter()
+        .map(|(hand, bid)| (Hand::new(hand, jokers), *bid))
+        .collect::<Vec<_>>();
+    hands_to_bid.sort_by(|(lhs, _), (rhs, _)| {
+        lhs.strength
+            .cmp(&rhs.strength)
+            .then(lhs.cards.cmp(&rhs.cards))
+    });
+    hands_to_bid
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (_, bid))| checked::mul(idx as i64 + 1, bid))
+        .try_checked_sum()
+}
+
+pub fn part1(input: &Input) -> Result<i64, Overflow> {
+    total_winnings(input, false)
+}
+
+pub fn part2(input: &Input) -> Result<i64, Overflow> {
+    total_winnings(input, true)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
@@ -0,0 +1,110 @@
+//! Random maps of the desert network.
+//!
+//! Each ghost walks a ring of its own: from its start node through pairs of
+//! nodes, where left and right always lead to the same pair, to its end
+//! node, whose exits are those of the start. Whatever the directions, every
+//! ghost reaches its end node after a fixed number of steps and again every
+//! as many steps, which is what part 2 relies on.
+
+use std::collections::HashSet;
+
+use aoc_core::{
+    checked,
+    generate::{Generator, RngExt, SliceRandom, StdRng},
+    Answer,
+};
+
+/// Sized by the number of nodes.
+pub const GENERATOR: Generator = Generator {
+    size: 706,
+    input,
+    answers: Some(answers),
+};
+
+fn name(rng: &mut StdRng, last: impl Fn(char) -> bool) -> String {
+    loop {
+        let name: String = (0..3)
+            .map(|_| char::from(rng.random_range(b'A'..=b'Z')))
+            .collect();
+        if last(name.chars().last().unwrap()) {
+            return name;
+        }
+    }
+}
+
+/// A name ending in `A` or `Z` as `last` says, or in neither, that is not
+/// taken yet.
+fn unique_name(rng: &mut StdRng, taken: &mut HashSet<String>, last: fn(char) -> bool) -> String {
+    loop {
+        let name = name(rng, last);
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn input(rng: &mut StdRng, nodes: usize) -> String {
+    network(rng, nodes).0
+}
+
+/// A ghost needs one step more than its ring has layers to reach its end
+/// node: part 1 follows the first ghost, part 2 waits for all of them.
+fn answers(rng: &mut StdRng, nodes: usize) -> [Option<Answer>; 2] {
+    let rings = network(rng, nodes).1;
+    let all = rings
+        .iter()
+        .try_fold(1, |all, &ring| checked::lcm(all, ring))
+        .expect("rings are short enough");
+    [Some(rings[0].into()), Some(all.into())]
+}
+
+/// A map and the number of steps around each ghost's ring.
+fn network(rng: &mut StdRng, nodes: usize) -> (String, Vec<usize>) {
+    let mut rings = vec![];
+    let ghosts = (nodes / 100).clamp(1, 6);
+    let mut taken = HashSet::from(["AAA".to_owned(), "ZZZ".to_owned()]);
+    let mut lines = vec![];
+    let pair = |rng: &mut StdRng, a: &str, b: &str| {
+        if rng.random_bool(0.5) {
+            format!("({a}, {b})")
+        } else {
+            format!("({b}, {a})")
+        }
+    };
+    for ghost in 0..ghosts {
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_owned(), "ZZZ".to_owned())
+        } else {
+            (
+                unique_name(rng, &mut taken, |ch| ch == 'A'),
+                unique_name(rng, &mut taken, |ch| ch == 'Z'),
+            )
+        };
+        // Rings of different lengths, so that part 2 takes their least
+        // common multiple.
+        let share = (nodes / ghosts).saturating_sub(2) / 2;
+        let layers = rng.random_range(share / 2..=share);
+        rings.push(layers + 1);
+        let ring: Vec<[String; 2]> = (0..layers)
+            .map(|_| [(); 2].map(|()| unique_name(rng, &mut taken, |ch| ch != 'A' && ch != 'Z')))
+            .collect();
+        let first = ring
+            .first()
+            .map_or([end.clone(), end.clone()], Clone::clone);
+        lines.push(format!("{start} = {}", pair(rng, &first[0], &first[1])));
+        lines.push(format!("{end} = {}", pair(rng, &first[0], &first[1])));
+        for (i, layer) in ring.iter().enumerate() {
+            let next = ring
+                .get(i + 1)
+                .map_or([end.clone(), end.clone()], Clone::clone);
+            for node in layer {
+                lines.push(format!("{node} = {}", pair(rng, &next[0], &next[1])));
+            }
+        }
+    }
+    lines.shuffle(rng);
+    let directions: String = (0..rng.random_range(1..=(nodes / 2).max(1)))
+        .map(|_| if rng.random_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+    (format!("{directions}\n\n{}\n", lines.join("\n")), rings)
+}
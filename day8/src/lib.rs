@@ -0,0 +1,136 @@
+//! Day 8: Haunted Wasteland
+
+pub mod generate;
+pub mod naive;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use aoc_core::{checked, parse, Answer, Overflow, ParseError, PartError, Solution};
+
+pub type Label = String;
+pub enum Direction {
+    R,
+    L,
+}
+
+pub struct Input {
+    pub directions: Vec<Direction>,
+    pub nodes: HashMap<Label, (Label, Label)>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let directions_line = lines.next().unwrap_or(s);
+        if directions_line.is_empty() {
+            return Err(ParseError::expected("`L` or `R`", directions_line));
+        }
+        let directions = directions_line
+            .char_indices()
+            .map(|(i, c)| match c {
+                'R' => Ok(Direction::R),
+                'L' => Ok(Direction::L),
+                _ => Err(ParseError::expected(
+                    "`L` or `R`",
+                    &directions_line[i..i + c.len_utf8()],
+                )),
+            })
+            .collect::<Result<Vec<Direction>, _>>()?;
+        lines.next();
+        let nodes = lines
+            .map(|line| {
+                let (src, dst) = parse::split_once(line, "=")?;
+                let src = src.trim().to_string();
+                let (left, right) = parse::split_once(
+                    parse::strip_suffix(parse::strip_prefix(dst.trim(), "(")?, ")")?,
+                    ",",
+                )?;
+                Ok((src, left.trim(), right.trim()))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        let labels: HashSet<&str> = nodes.iter().map(|(src, ..)| src.as_str()).collect();
+        if let Some(&unknown) = nodes
+            .iter()
+            .flat_map(|(_, left, right)| [left, right])
+            .find(|&&label| !labels.contains(label))
+        {
+            return Err(ParseError::expected("a node of the map", unknown));
+        }
+        let nodes = nodes
+            .into_iter()
+            .map(|(src, left, right)| (src, (left.to_owned(), right.to_owned())))
+            .collect();
+        Ok(Self { directions, nodes })
+    }
+}
+
+pub fn num_steps(
+    nodes: &HashMap<Label, (Label, Label)>,
+    directions: &[Direction],
+    src: Label,
+) -> Result<usize, Overflow> {
+    let mut node = &src;
+    let mut steps: usize = 0;
+    loop {
+        let (left, right) = &nodes[node];
+        node = match directions[steps % directions.len()] {
+            Direction::L => left,
+            Direction::R => right,
+        };
+        steps = checked::add(steps, 1)?;
+        if node.ends_with('Z') {
+            return Ok(steps);
+        }
+    }
+}
+
+pub fn part1(input: &Input) -> Result<usize, PartError> {
+    if !input.nodes.contains_key("AAA") {
+        return Err(PartError::no_answer("there is no node `AAA`"));
+    }
+    Ok(num_steps(
+        &input.nodes,
+        &input.directions,
+        "AAA".to_owned(),
+    )?)
+}
+
+pub fn part2(input: &Input) -> Result<usize, PartError> {
+    let starts: Vec<&Label> = input
+        .nodes
+        .keys()
+        .filter(|src| src.ends_with('A'))
+        .collect();
+    if starts.is_empty() {
+        return Err(PartError::no_answer("no node ends in `A`"));
+    }
+    Ok(starts.into_iter().try_fold(1, |steps, src| {
+        checked::lcm(
+            steps,
+            num_steps(&input.nodes, &input.directions, src.clone())?,
+        )
+    })?)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
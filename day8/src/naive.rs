@@ -0,0 +1,26 @@
+//! Straightforward solutions to check the optimised ones against.
+
+use crate::{Direction, Input};
+
+/// Moves every ghost at once until they all stand on nodes ending in `Z`.
+pub fn part2(input: &Input) -> usize {
+    let mut ghosts: Vec<&str> = input
+        .nodes
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .map(String::as_str)
+        .collect();
+    for (steps, dir) in input.directions.iter().cycle().enumerate() {
+        for ghost in &mut ghosts {
+            let (left, right) = &input.nodes[*ghost];
+            *ghost = match dir {
+                Direction::L => left,
+                Direction::R => right,
+            };
+        }
+        if ghosts.iter().all(|ghost| ghost.ends_with('Z')) {
+            return steps + 1;
+        }
+    }
+    unreachable!("the directions repeat forever")
+}
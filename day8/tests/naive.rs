@@ -0,0 +1,16 @@
+use day8::{generate::GENERATOR, naive, Input};
+
+#[test]
+fn lcm_matches_moving_every_ghost() {
+    for seed in 0..10 {
+        // Two or three ghosts on short loops, so that they meet soon.
+        let nodes = [200, 250, 300][seed as usize % 3];
+        let text = GENERATOR.generate(seed, nodes);
+        let input: Input = text.parse().unwrap();
+        assert_eq!(
+            day8::part2(&input),
+            Ok(naive::part2(&input)),
+            "seed {seed}:\n{text}"
+        );
+    }
+}
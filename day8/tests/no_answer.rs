@@ -0,0 +1,20 @@
+use aoc_core::PartError;
+use day8::Input;
+
+#[test]
+fn ghosts_need_a_start() {
+    let input: Input = "L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n".parse().unwrap();
+    assert_eq!(
+        day8::part2(&input),
+        Err(PartError::no_answer("no node ends in `A`"))
+    );
+}
+
+#[test]
+fn part1_needs_a_node_aaa() {
+    let input: Input = "L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n".parse().unwrap();
+    assert_eq!(
+        day8::part1(&input),
+        Err(PartError::no_answer("there is no node `AAA`"))
+    );
+}
@@ -0,0 +1,68 @@
+//! Random OASIS reports.
+
+use aoc_core::{
+    generate::{Generator, RngExt, StdRng},
+    Answer,
+};
+
+/// Values shown per history.
+const VALUES: usize = 21;
+/// Bound on every value and difference, extrapolated ones included, which
+/// keeps the histories as tame as the puzzle's own.
+const BOUND: i64 = 1_000_000;
+
+/// Sized by the number of histories.
+pub const GENERATOR: Generator = Generator {
+    size: 200,
+    input,
+    answers: Some(answers),
+};
+
+/// Rows of the difference table of a polynomial sequence of `degree`, from
+/// one value before the history to one after it.
+fn differences(rng: &mut StdRng, degree: usize) -> Vec<Vec<i64>> {
+    // The last row is constant; each row above sums the one below.
+    let mut rows = vec![vec![rng.random_range(-10..=10); VALUES + 2]];
+    for _ in 0..degree {
+        let below = rows.last().unwrap();
+        let mut row = vec![rng.random_range(-20..=20)];
+        for i in 0..VALUES + 1 {
+            row.push(row[i] + below[i]);
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+fn input(rng: &mut StdRng, histories: usize) -> String {
+    report(rng, histories).0
+}
+
+/// Both parts add up values the polynomials take just outside the
+/// histories.
+fn answers(rng: &mut StdRng, histories: usize) -> [Option<Answer>; 2] {
+    let [next, previous] = report(rng, histories).1;
+    [Some(next.into()), Some(previous.into())]
+}
+
+/// Histories following polynomials of degree up to 8, and the sums of the
+/// values after and before them.
+fn report(rng: &mut StdRng, histories: usize) -> (String, [i64; 2]) {
+    let mut s = String::new();
+    let mut sums = [0, 0];
+    for _ in 0..histories {
+        let values = loop {
+            let degree = rng.random_range(0..=8);
+            let rows = differences(rng, degree);
+            if rows.iter().flatten().all(|value| value.abs() <= BOUND) {
+                break rows.last().unwrap().clone();
+            }
+        };
+        sums[0] += values[VALUES + 1];
+        sums[1] += values[0];
+        let values: Vec<String> = values[1..=VALUES].iter().map(i64::to_string).collect();
+        s.push_str(&values.join(" "));
+        s.push('\n');
+    }
+    (s, sums)
+}
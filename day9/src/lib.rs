@@ -0,0 +1,82 @@
+//! Day 9: Mirage Maintenance
+
+pub mod generate;
+
+use std::str::FromStr;
+
+use aoc_core::{
+    checked::{self, CheckedIterator},
+    parse, Answer, Overflow, ParseError, PartError, Solution,
+};
+
+pub struct Input {
+    pub arrs: Vec<Vec<i64>>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let arrs = s
+            .lines()
+            .map(|line| {
+                if line.trim().is_empty() {
+                    return Err(ParseError::expected("a number", line));
+                }
+                line.split_whitespace().map(parse::number).collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { arrs })
+    }
+}
+
+fn adjacent_difference(arr: Vec<i64>) -> Result<Vec<i64>, Overflow> {
+    arr.windows(2)
+        .filter_map(|pairs| match pairs {
+            [a, b] => Some(checked::sub(*b, *a)),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn find_next(arr: Vec<i64>) -> Result<i64, Overflow> {
+    if arr.is_empty() || arr.iter().all(|&x| x == 0) {
+        return Ok(0);
+    }
+    let last = *arr.last().unwrap();
+    checked::add(last, find_next(adjacent_difference(arr)?)?)
+}
+
+pub fn part1(input: &Input) -> Result<i64, Overflow> {
+    input.arrs.iter().cloned().map(find_next).try_checked_sum()
+}
+
+pub fn part2(input: &Input) -> Result<i64, Overflow> {
+    input
+        .arrs
+        .iter()
+        .cloned()
+        .map(|mut arr| {
+            arr.reverse();
+            find_next(arr)
+        })
+        .try_checked_sum()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Input) -> Result<Answer, PartError> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, PartError> {
+        Ok(part2(input)?.into())
+    }
+}
@@ -0,0 +1,17 @@
+use aoc_core::{PartError, Solution};
+use day9::{Day9, Input};
+
+#[test]
+fn extrapolates_beyond_i32() {
+    let input: Input = "0 2000000000 4000000000\n".parse().unwrap();
+    assert_eq!(Day9::part1(&input), Ok(6_000_000_000i64.into()));
+    assert_eq!(Day9::part2(&input), Ok((-2_000_000_000i64).into()));
+}
+
+#[test]
+fn extrapolating_beyond_i64_overflows() {
+    let input: Input = format!("0 {} {}\n", i64::MAX / 2, i64::MAX - 1)
+        .parse()
+        .unwrap();
+    assert_eq!(Day9::part1(&input), Err(PartError::Overflow));
+}